@@ -0,0 +1,57 @@
+//! Generate a test for every expected answer listed in `examples/expected.txt`
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo::rerun-if-changed=examples");
+
+    let manifest = fs::read_to_string("examples/expected.txt")
+        .expect("examples/expected.txt should list the expected answers");
+
+    let mut tests = String::new();
+
+    for (i, line) in manifest.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields = line.splitn(3, ' ');
+        let (Some(example), Some(part), Some(answer)) =
+            (fields.next(), fields.next(), fields.next())
+        else {
+            panic!(
+                "examples/expected.txt:{}: expected `<example> <part> <answer>`",
+                i + 1
+            );
+        };
+
+        let variant = match part {
+            "one" => "One",
+            "two" => "Two",
+            _ => panic!("examples/expected.txt:{}: invalid part {part:?}", i + 1),
+        };
+
+        let ident: String = example
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        tests += &format!(
+            "#[test]\n\
+             fn {ident}_part_{part}() {{\n    \
+                 check_example({example:?}, Part::{variant}, {:?});\n\
+             }}\n\n",
+            answer.trim()
+        );
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("example_tests.rs");
+    fs::write(out, tests).unwrap();
+}
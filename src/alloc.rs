@@ -0,0 +1,177 @@
+//! Allocation counts of the parse step and the parts of a day
+//!
+//! Only built with the `count-alloc` feature, which replaces the global
+//! allocator by a wrapper of the system one counting every allocation. The
+//! counters are shared by all threads, so phases are measured one at a time.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    error::Error,
+    sync::atomic::{AtomicUsize, Ordering::Relaxed},
+};
+
+use crate::{
+    Part,
+    bench::Phase,
+    input::{Dirs, Source},
+    registry::Day,
+};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// System allocator counting allocations, reallocations included, and the
+/// bytes they ask for
+struct Counting;
+
+fn allocated(size: usize) {
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    BYTES.fetch_add(size, Relaxed);
+    let live = LIVE.fetch_add(size, Relaxed) + size;
+    PEAK.fetch_max(live, Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            LIVE.fetch_sub(layout.size(), Relaxed);
+            allocated(new_size);
+        }
+        new
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        LIVE.fetch_sub(layout.size(), Relaxed);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Usage {
+    pub allocations: usize,
+    pub bytes: usize,
+    /// Highest number of bytes live at once, over those live before
+    pub peak: usize,
+}
+
+/// Run `f`, counting its allocations
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let live = LIVE.load(Relaxed);
+    PEAK.store(live, Relaxed);
+    let (allocations, bytes) = (ALLOCATIONS.load(Relaxed), BYTES.load(Relaxed));
+
+    let result = f();
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+        bytes: BYTES.load(Relaxed) - bytes,
+        peak: PEAK.load(Relaxed).saturating_sub(live),
+    };
+    (result, usage)
+}
+
+/// Count the allocations of the parse step and of every part of a day
+pub fn profile(
+    day: &Day,
+    source: &Source,
+    dirs: &Dirs,
+    parts: &[Part],
+) -> Result<Vec<(Phase, Usage)>, Box<dyn Error + Send + Sync>> {
+    let input = source.read(day.number, dirs)?;
+
+    let (parsed, usage) = measure(|| day.parse(&input));
+    let parsed = parsed?;
+    let mut results = vec![(Phase::Parse, usage)];
+
+    for &part in parts {
+        let (_, usage) = measure(|| parsed.solve(part));
+        results.push((Phase::Part(part), usage));
+    }
+
+    Ok(results)
+}
+
+/// Byte count in binary units
+fn size(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+pub fn print_header() {
+    println!(
+        "{:<5}{:<10}{:>14}{:>14}{:>14}",
+        "Day", "Phase", "Allocations", "Bytes", "Peak"
+    );
+}
+
+pub fn print_usage(day: u8, phase: Phase, usage: &Usage) {
+    println!(
+        "{:<5}{:<10}{:>14}{:>14}{:>14}",
+        format!("{day:02}"),
+        phase.to_string(),
+        usage.allocations,
+        size(usage.bytes),
+        size(usage.peak),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        // Other tests allocate at the same time, which can only add to the
+        // counts, while their frees can hide part of the peak
+        let (_, usage) = measure(|| {
+            let numbers: Vec<u64> = black_box((0..1000).collect());
+            drop(numbers);
+            black_box(vec![0u8; 100])
+        });
+
+        assert!(usage.allocations >= 2, "{usage:?}");
+        assert!(usage.bytes >= 8100, "{usage:?}");
+    }
+
+    #[test]
+    fn test_size() {
+        assert_eq!(size(512), "512 B");
+        assert_eq!(size(1536), "1.5 KiB");
+        assert_eq!(size(3 << 30), "3.0 GiB");
+    }
+}
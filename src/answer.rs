@@ -0,0 +1,253 @@
+//! Answers of the puzzle parts, from plain integers to letter-art strings
+
+use std::{
+    cmp::Ordering,
+    convert::Infallible,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Arbitrary-precision decimal integer, for answers not fitting 128 bits
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    /// Decimal digits without leading zeros, `0` for zero
+    digits: String,
+}
+
+impl FromStr for BigInt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("invalid integer {s:?}"));
+        }
+
+        let digits = match digits.trim_start_matches('0') {
+            "" => "0",
+            digits => digits,
+        };
+
+        Ok(BigInt {
+            negative: negative && digits != "0",
+            digits: digits.to_string(),
+        })
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(n: i128) -> Self {
+        n.to_string().parse().unwrap()
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(n: u128) -> Self {
+        n.to_string().parse().unwrap()
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let magnitude = self
+            .digits
+            .len()
+            .cmp(&other.digits.len())
+            .then_with(|| self.digits.cmp(&other.digits));
+
+        match (self.negative, other.negative) {
+            (false, false) => magnitude,
+            (true, true) => magnitude.reverse(),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", self.digits)
+    }
+}
+
+/// Answer of a puzzle part
+///
+/// Numeric answers compare by value whatever their variant, so an answer
+/// computed as `u64` matches the same number read back from a file.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Wide(i128),
+    Big(BigInt),
+    /// Non numeric answers, like the letters of a letter-art puzzle
+    Text(String),
+}
+
+impl Answer {
+    /// Value of a numeric answer
+    pub fn number(&self) -> Option<BigInt> {
+        match self {
+            Answer::Signed(n) => Some(BigInt::from(*n as i128)),
+            Answer::Unsigned(n) => Some(BigInt::from(*n as u128)),
+            Answer::Wide(n) => Some(BigInt::from(*n)),
+            Answer::Big(n) => Some(n.clone()),
+            Answer::Text(_) => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl Eq for Answer {}
+
+impl PartialOrd for Answer {
+    /// Numbers compare by value and text by content, but never with each other
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => Some(a.cmp(b)),
+            (Answer::Text(_), _) | (_, Answer::Text(_)) => None,
+            (a, b) => Some(a.number().cmp(&b.number())),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Wide(n) => write!(f, "{n}"),
+            Answer::Big(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Read back a displayed answer in the smallest variant that fits it
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let answer = if let Ok(n) = s.parse() {
+            Answer::Signed(n)
+        } else if let Ok(n) = s.parse() {
+            Answer::Unsigned(n)
+        } else if let Ok(n) = s.parse() {
+            Answer::Wide(n)
+        } else if let Ok(n) = s.parse() {
+            Answer::Big(n)
+        } else {
+            Answer::Text(s.to_string())
+        };
+
+        Ok(answer)
+    }
+}
+
+macro_rules! impl_from {
+    ($($t:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(
+    i8 => Signed,
+    i16 => Signed,
+    i32 => Signed,
+    i64 => Signed,
+    u8 => Unsigned,
+    u16 => Unsigned,
+    u32 => Unsigned,
+    u64 => Unsigned,
+    i128 => Wide,
+    BigInt => Big,
+    String => Text,
+    &str => Text,
+);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Unsigned(n as u64)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Wide(n),
+            Err(_) => Answer::Big(n.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_across_variants() {
+        assert_eq!(Answer::from(16u64), Answer::from(16i32));
+        assert_eq!(
+            Answer::from(u128::MAX),
+            u128::MAX.to_string().parse().unwrap()
+        );
+        assert!(Answer::from(-3i64) < Answer::from(2u64));
+        assert!(Answer::from(u64::MAX) < Answer::Wide(u64::MAX as i128 + 1));
+        assert_ne!(Answer::from("16"), Answer::from(16u64));
+        assert_eq!(Answer::from("16").partial_cmp(&Answer::from(16u64)), None);
+    }
+
+    #[test]
+    fn test_from_str() {
+        let big = "123456789012345678901234567890123456789012";
+
+        assert!(matches!("-16".parse(), Ok(Answer::Signed(-16))));
+        assert!(matches!(
+            "18446744073709551615".parse(),
+            Ok(Answer::Unsigned(u64::MAX))
+        ));
+        assert!(matches!(
+            "18446744073709551616".parse(),
+            Ok(Answer::Wide(_))
+        ));
+        assert!(matches!(big.parse(), Ok(Answer::Big(_))));
+        assert!(matches!("EFJKZLAU".parse(), Ok(Answer::Text(_))));
+        assert_eq!(big.parse::<Answer>().unwrap().to_string(), big);
+    }
+
+    #[test]
+    fn test_big_int() {
+        let n = |s: &str| s.parse::<BigInt>().unwrap();
+
+        assert_eq!(n("-000"), n("0"));
+        assert_eq!(n("+0042").to_string(), "42");
+        assert!(n("-100") < n("-99"));
+        assert!(n("99") < n("100"));
+        assert!("4-2".parse::<BigInt>().is_err());
+    }
+}
@@ -0,0 +1,354 @@
+//! Registry of the answers submitted for each day
+//!
+//! Every day has an `answers/day_XX.txt` file with one answer per line,
+//! prefixed by the part and what the puzzle said about it:
+//!
+//! ```text
+//! # Wrong guesses before getting it right
+//! two too-low 2047589286843
+//! two confirmed 2047589286999
+//! ```
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    fs::{self, File},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{Answer, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Confirmed,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Verdict {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "confirmed" => Some(Verdict::Confirmed),
+            "too-high" => Some(Verdict::TooHigh),
+            "too-low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Confirmed => write!(f, "confirmed"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: Answer,
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.part, self.verdict, self.answer)
+    }
+}
+
+/// How a computed answer relates to the known answers of its part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Confirmed,
+    Differs { confirmed: Answer },
+    KnownWrong,
+    ViolatesBound { verdict: Verdict, guess: Answer },
+    New,
+}
+
+impl Status {
+    /// Identifier of the status for machine-readable output
+    pub fn key(&self) -> &'static str {
+        match self {
+            Status::Confirmed => "confirmed",
+            Status::Differs { .. } => "differs",
+            Status::KnownWrong => "known-wrong",
+            Status::ViolatesBound { .. } => "violates-bound",
+            Status::New => "new",
+        }
+    }
+
+    /// Whether the answer is known not to be the right one
+    pub fn is_regression(&self) -> bool {
+        matches!(
+            self,
+            Status::Differs { .. } | Status::KnownWrong | Status::ViolatesBound { .. }
+        )
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Confirmed => write!(f, "matches confirmed"),
+            Status::Differs { confirmed } => write!(f, "differs from confirmed {confirmed}"),
+            Status::KnownWrong => write!(f, "known wrong"),
+            Status::ViolatesBound { verdict, guess } => {
+                let bound = if *verdict == Verdict::TooLow {
+                    '>'
+                } else {
+                    '<'
+                };
+                write!(f, "violates known bound, must be {bound} {guess}")
+            }
+            Status::New => write!(f, "new"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: Vec<Entry>,
+}
+
+fn parse_part(s: &str) -> Option<Part> {
+    match s {
+        "one" => Some(Part::One),
+        "two" => Some(Part::Two),
+        _ => None,
+    }
+}
+
+impl Answers {
+    pub fn new(entries: Vec<Entry>) -> Self {
+        Answers { entries }
+    }
+
+    pub fn path(dir: &Path, day: u8) -> PathBuf {
+        dir.join(format!("day_{day:02}.txt"))
+    }
+
+    /// Load the answers of a day, a missing file meaning nothing is known yet
+    pub fn load(dir: &Path, day: u8) -> Result<Self, String> {
+        let path = Self::path(dir, day);
+
+        match fs::read_to_string(&path) {
+            Ok(content) => {
+                Self::parse(&content).map_err(|err| format!("{}: {err}", path.display()))
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("failed to read {}: {err}", path.display())),
+        }
+    }
+
+    /// Append an entry to the answers file of a day, creating it if needed
+    pub fn record(dir: &Path, day: u8, entry: &Entry) -> Result<(), String> {
+        let path = Self::path(dir, day);
+        let error = |err| format!("failed to write {}: {err}", path.display());
+
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(format!("failed to read {}: {err}", path.display())),
+        };
+
+        fs::create_dir_all(dir).map_err(error)?;
+        let mut file = File::options()
+            .append(true)
+            .create(true)
+            .open(&path)
+            .map_err(error)?;
+        // A hand edited file may lack its final newline
+        if !content.is_empty() && !content.ends_with('\n') {
+            writeln!(file).map_err(error)?;
+        }
+        writeln!(file, "{entry}").map_err(error)
+    }
+
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let entries = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|(i, line)| {
+                let mut fields = line.splitn(3, ' ');
+                let part = fields.next().and_then(parse_part);
+                let verdict = fields.next().and_then(Verdict::parse);
+                let answer = fields.next().map(str::trim);
+
+                match (part, verdict, answer) {
+                    (Some(part), Some(verdict), Some(answer)) if !answer.is_empty() => Ok(Entry {
+                        part,
+                        verdict,
+                        answer: answer.parse().unwrap(),
+                    }),
+                    _ => Err(format!(
+                        "line {}: expected `<one|two> <verdict> <answer>`",
+                        i + 1
+                    )),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Answers { entries })
+    }
+
+    pub fn entries(&self, part: Part) -> impl Iterator<Item = &Entry> {
+        self.entries.iter().filter(move |entry| entry.part == part)
+    }
+
+    pub fn check(&self, part: Part, answer: &Answer) -> Status {
+        if let Some(entry) = self
+            .entries(part)
+            .find(|entry| entry.verdict == Verdict::Confirmed)
+        {
+            return if entry.answer == *answer {
+                Status::Confirmed
+            } else {
+                Status::Differs {
+                    confirmed: entry.answer.clone(),
+                }
+            };
+        }
+
+        if self.entries(part).any(|entry| entry.answer == *answer) {
+            return Status::KnownWrong;
+        }
+
+        // Bounds only compare answers of the same kind, numbers or text
+        let violated = self.entries(part).find(|entry| {
+            let ordering = answer.partial_cmp(&entry.answer);
+
+            match entry.verdict {
+                Verdict::TooHigh => ordering.is_some_and(Ordering::is_ge),
+                Verdict::TooLow => ordering.is_some_and(Ordering::is_le),
+                _ => false,
+            }
+        });
+
+        match violated {
+            Some(entry) => Status::ViolatesBound {
+                verdict: entry.verdict,
+                guess: entry.answer.clone(),
+            },
+            None => Status::New,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::scratch_path;
+
+    const ANSWERS: &str = "# Day 06
+two too-low 2047589286843
+two too-high 3000000000000
+two wrong 2500000000000
+
+one confirmed 4277556
+";
+
+    fn answer(s: &str) -> Answer {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(
+            answers.check(Part::One, &answer("4277556")),
+            Status::Confirmed
+        );
+        assert_eq!(
+            answers.check(Part::One, &answer("4277557")),
+            Status::Differs {
+                confirmed: answer("4277556")
+            }
+        );
+        assert_eq!(
+            answers.check(Part::Two, &answer("2500000000000")),
+            Status::KnownWrong
+        );
+        assert_eq!(
+            answers.check(Part::Two, &answer("2047589286800")),
+            Status::ViolatesBound {
+                verdict: Verdict::TooLow,
+                guess: answer("2047589286843")
+            }
+        );
+        assert_eq!(
+            answers.check(Part::Two, &answer("2047589286844")),
+            Status::New
+        );
+    }
+
+    /// The bins asserted their part two answers were above these guesses
+    #[test]
+    fn test_guards_of_the_bins_are_kept() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("answers");
+
+        for (day, guess) in [(6, 2047589286843u64), (7, 3110), (8, 673096640)] {
+            let answers = Answers::load(&dir, day).unwrap();
+
+            assert_eq!(
+                answers.check(Part::Two, &Answer::from(guess - 1)),
+                Status::ViolatesBound {
+                    verdict: Verdict::TooLow,
+                    guess: Answer::from(guess)
+                },
+                "day {day} lost its guard"
+            );
+        }
+    }
+
+    #[test]
+    fn test_record() {
+        let dir = scratch_path("answers");
+        let entry = Entry {
+            part: Part::Two,
+            verdict: Verdict::TooHigh,
+            answer: answer("3000000000000"),
+        };
+
+        Answers::record(&dir, 6, &entry).unwrap();
+        Answers::record(&dir, 6, &entry).unwrap();
+        let result = Answers::load(&dir, 6);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(result, Ok(Answers::new(vec![entry.clone(), entry])));
+    }
+
+    #[test]
+    fn test_record_after_missing_newline() {
+        let dir = scratch_path("answers-newline");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(Answers::path(&dir, 7), "two too-low 3110").unwrap();
+        let entry = Entry {
+            part: Part::Two,
+            verdict: Verdict::TooHigh,
+            answer: answer("6000"),
+        };
+
+        Answers::record(&dir, 7, &entry).unwrap();
+        let content = fs::read_to_string(Answers::path(&dir, 7));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(content.unwrap(), "two too-low 3110\ntwo too-high 6000\n");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Answers::parse("three confirmed 1").is_err());
+        assert!(Answers::parse("one maybe 1").is_err());
+        assert!(Answers::parse("one confirmed").is_err());
+    }
+}
@@ -0,0 +1,286 @@
+//! Benchmark results saved on disk, to compare later runs against
+//!
+//! Each line of the baseline file holds the statistics of one phase of a day
+//! on one input, with the times in nanoseconds:
+//!
+//! ```text
+//! <day> <source> <phase> <samples> <min> <median> <mean> <stddev> <outliers>
+//! ```
+//!
+//! Timings are only compared between runs on the same input, see [`source_key`].
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs,
+    io::{self, ErrorKind},
+    path::Path,
+    time::Duration,
+};
+
+use crate::{
+    bench::{Phase, Stats},
+    input::Source,
+};
+
+/// Welch's t statistic above which a difference is significant, ~99% confidence
+const SIGNIFICANT_T: f64 = 2.6;
+/// Relative differences below this are ignored even when significant
+const NOISE_THRESHOLD: f64 = 0.02;
+
+/// Identifier of the input of a run, without whitespace, `None` for stdin
+/// whose content can change from one run to the next
+pub fn source_key(source: &Source) -> Option<String> {
+    match source {
+        Source::Puzzle => Some("puzzle".to_string()),
+        Source::Example(None) => Some("example".to_string()),
+        Source::Example(Some(name)) => Some(format!("example:{name}")),
+        Source::File(path) => Some(format!(
+            "file:{}",
+            path.display()
+                .to_string()
+                .replace('%', "%25")
+                .replace(char::is_whitespace, "%20")
+        )),
+        Source::Stdin => None,
+    }
+}
+
+type Key = (u8, String, String);
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Baseline {
+    entries: BTreeMap<Key, Stats>,
+}
+
+fn parse_line(line: &str) -> Option<(Key, Stats)> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let [
+        day,
+        source,
+        phase,
+        samples,
+        min,
+        median,
+        mean,
+        stddev,
+        outliers,
+    ] = fields[..]
+    else {
+        return None;
+    };
+
+    let nanos = |s: &str| s.parse().ok().map(Duration::from_nanos);
+
+    let stats = Stats {
+        samples: samples.parse().ok()?,
+        min: nanos(min)?,
+        median: nanos(median)?,
+        mean: nanos(mean)?,
+        stddev: nanos(stddev)?,
+        outliers: outliers.parse().ok()?,
+    };
+
+    Some((
+        (day.parse().ok()?, source.to_string(), phase.to_string()),
+        stats,
+    ))
+}
+
+impl Baseline {
+    /// Load a baseline, a missing file meaning nothing was saved yet
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(format!("failed to read {}: {err}", path.display())),
+        };
+
+        let entries = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            // Lines saved before the input was recorded cannot be compared
+            .filter(|(_, line)| line.split_whitespace().count() != 8)
+            .map(|(i, line)| {
+                parse_line(line)
+                    .ok_or_else(|| format!("{}: invalid line {}", path.display(), i + 1))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Baseline { entries })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let content: String = self
+            .entries
+            .iter()
+            .map(|((day, source, phase), stats)| {
+                format!(
+                    "{day} {source} {phase} {} {} {} {} {} {}\n",
+                    stats.samples,
+                    stats.min.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.mean.as_nanos(),
+                    stats.stddev.as_nanos(),
+                    stats.outliers,
+                )
+            })
+            .collect();
+
+        fs::write(path, content)
+    }
+
+    /// Saved statistics of a phase on the same input, see [`source_key`]
+    pub fn get(&self, day: u8, source: &str, phase: Phase) -> Option<&Stats> {
+        self.entries.get(&(day, source.to_string(), phase.key()))
+    }
+
+    pub fn insert(&mut self, day: u8, source: &str, phase: Phase, stats: Stats) {
+        self.entries
+            .insert((day, source.to_string(), phase.key()), stats);
+    }
+}
+
+/// Change of the mean time compared to the baseline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    Slower(f64),
+    Faster(f64),
+    /// Difference within noise or not statistically significant
+    Unchanged(f64),
+}
+
+impl Change {
+    /// Compare two benchmarks with Welch's t-test on their means
+    pub fn between(baseline: &Stats, current: &Stats) -> Self {
+        let (old, new) = (baseline.mean.as_secs_f64(), current.mean.as_secs_f64());
+        if old == 0.0 {
+            return Change::Unchanged(0.0);
+        }
+
+        let relative = (new - old) / old;
+
+        let error = baseline.stddev.as_secs_f64().powi(2) / baseline.samples as f64
+            + current.stddev.as_secs_f64().powi(2) / current.samples as f64;
+        let t = (new - old) / error.sqrt();
+
+        // Without any spread in the samples the t statistic is not a number
+        let significant = t.is_nan() || t.abs() >= SIGNIFICANT_T;
+
+        match relative {
+            r if !significant || r.abs() < NOISE_THRESHOLD => Change::Unchanged(relative),
+            r if r > 0.0 => Change::Slower(relative),
+            _ => Change::Faster(relative),
+        }
+    }
+
+    pub fn is_regression(&self) -> bool {
+        matches!(self, Change::Slower(_))
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::Slower(r) => write!(f, "{:+.1}% slower", r * 100.0),
+            Change::Faster(r) => write!(f, "{:+.1}% faster", r * 100.0),
+            Change::Unchanged(r) => write!(f, "{:+.1}% ~", r * 100.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Part, input::scratch_path};
+
+    fn stats(mean_ms: u64, stddev_ms: u64) -> Stats {
+        Stats {
+            samples: 100,
+            min: Duration::from_millis(mean_ms.saturating_sub(stddev_ms)),
+            median: Duration::from_millis(mean_ms),
+            mean: Duration::from_millis(mean_ms),
+            stddev: Duration::from_millis(stddev_ms),
+            outliers: 2,
+        }
+    }
+
+    #[test]
+    fn test_change() {
+        let baseline = stats(100, 5);
+
+        assert!(matches!(
+            Change::between(&baseline, &stats(120, 5)),
+            Change::Slower(_)
+        ));
+        assert!(matches!(
+            Change::between(&baseline, &stats(80, 5)),
+            Change::Faster(_)
+        ));
+        assert!(matches!(
+            Change::between(&baseline, &stats(101, 5)),
+            Change::Unchanged(_)
+        ));
+        // Big but noisy difference
+        assert!(matches!(
+            Change::between(&baseline, &stats(120, 200)),
+            Change::Unchanged(_)
+        ));
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = scratch_path("baseline").with_extension("txt");
+
+        let mut baseline = Baseline::default();
+        baseline.insert(8, "puzzle", Phase::Part(Part::Two), stats(6000, 100));
+        baseline.insert(8, "example", Phase::Part(Part::Two), stats(1, 0));
+        baseline.insert(3, "puzzle", Phase::Parse, stats(2, 1));
+        baseline.save(&path).unwrap();
+
+        let result = Baseline::load(&path).unwrap();
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(result, baseline);
+        assert_eq!(
+            result.get(8, "puzzle", Phase::Part(Part::Two)),
+            Some(&stats(6000, 100))
+        );
+        assert_eq!(
+            result.get(8, "example", Phase::Part(Part::Two)),
+            Some(&stats(1, 0))
+        );
+        assert_eq!(result.get(8, "puzzle", Phase::Parse), None);
+        assert_eq!(result.get(3, "example:big", Phase::Parse), None);
+    }
+
+    #[test]
+    fn test_load_skips_lines_without_source() {
+        let path = scratch_path("old-baseline").with_extension("txt");
+        fs::write(&path, "8 part_two 100 1 2 3 4 5\n").unwrap();
+
+        let result = Baseline::load(&path);
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(result, Ok(Baseline::default()));
+    }
+
+    #[test]
+    fn test_source_key() {
+        assert_eq!(source_key(&Source::Puzzle), Some("puzzle".to_string()));
+        assert_eq!(
+            source_key(&Source::Example(Some("big".to_string()))),
+            Some("example:big".to_string())
+        );
+        assert_eq!(
+            source_key(&Source::File("my input%.txt".into())),
+            Some("file:my%20input%25.txt".to_string())
+        );
+        assert_eq!(source_key(&Source::Stdin), None);
+    }
+}
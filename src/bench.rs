@@ -0,0 +1,251 @@
+//! Statistical benchmarking of the parse step and the parts of a day
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    Part,
+    baseline::Change,
+    input::{Dirs, Source},
+    registry::Day,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Time spent running the code before measuring
+    pub warmup: Duration,
+    pub samples: usize,
+    /// Minimum time of a sample, fast code is run several times per sample
+    pub sample_time: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            warmup: Duration::from_millis(500),
+            samples: 100,
+            sample_time: Duration::from_millis(1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+    /// Alternative implementation of a part, by name
+    Alternative(Part, &'static str),
+}
+
+impl Phase {
+    /// Identifier without spaces, used in files
+    pub fn key(&self) -> String {
+        match self {
+            Phase::Parse => "parse".to_string(),
+            Phase::Part(part) => format!("part_{part}"),
+            Phase::Alternative(part, name) => format!("part_{part}:{name}"),
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part {part}"),
+            Phase::Alternative(part, name) => write!(f, "part {part} {name}"),
+        }
+    }
+}
+
+/// Summary of the time per iteration over all samples
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    /// Samples outside the 1.5 IQR Tukey fences
+    pub outliers: usize,
+}
+
+/// Linear interpolation between the closest ranks of sorted values
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let rank = q * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+impl Stats {
+    /// Compute the statistics of samples given in seconds
+    pub fn from_samples(samples: &[f64]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+
+        let n = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / n;
+        let variance = match sorted.len() {
+            1 => 0.0,
+            _ => sorted.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1.0),
+        };
+
+        let q1 = quantile(&sorted, 0.25);
+        let q3 = quantile(&sorted, 0.75);
+        let fence = 1.5 * (q3 - q1);
+        let outliers = sorted
+            .iter()
+            .filter(|&&s| s < q1 - fence || s > q3 + fence)
+            .count();
+
+        Stats {
+            samples: sorted.len(),
+            min: Duration::from_secs_f64(sorted[0]),
+            median: Duration::from_secs_f64(quantile(&sorted, 0.5)),
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            outliers,
+        }
+    }
+}
+
+/// Measure `f` after warming up, returning the time per iteration in seconds
+/// of every sample
+pub fn sample<T>(config: &Config, mut f: impl FnMut() -> T) -> Vec<f64> {
+    let start = Instant::now();
+    let mut iterations = 0u32;
+
+    while iterations == 0 || start.elapsed() < config.warmup {
+        black_box(f());
+        iterations += 1;
+    }
+
+    // Batch fast code so each sample is long enough to be measured reliably,
+    // code too fast for the clock is taken to run in a nanosecond
+    let estimate = (start.elapsed().as_secs_f64() / iterations as f64).max(1e-9);
+    let batch = (config.sample_time.as_secs_f64() / estimate)
+        .ceil()
+        .max(1.0) as u32;
+
+    (0..config.samples)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..batch {
+                black_box(f());
+            }
+            start.elapsed().as_secs_f64() / batch as f64
+        })
+        .collect()
+}
+
+/// Benchmark the parse step and the requested parts of a day, with their
+/// alternative implementations
+pub fn bench(
+    day: &Day,
+    source: &Source,
+    dirs: &Dirs,
+    parts: &[Part],
+    config: &Config,
+) -> Result<Vec<(Phase, Stats)>, Box<dyn Error + Send + Sync>> {
+    let input = source.read(day.number, dirs)?;
+    let parsed = day.parse(&input)?;
+
+    let mut results = vec![(
+        Phase::Parse,
+        Stats::from_samples(&sample(config, || day.parse(&input))),
+    )];
+
+    for &part in parts {
+        let samples = sample(config, || parsed.solve(part));
+        results.push((Phase::Part(part), Stats::from_samples(&samples)));
+
+        for (name, _) in day.alternatives().into_iter().filter(|&(_, p)| p == part) {
+            let samples = sample(config, || parsed.solve_alternative(part, name));
+            results.push((
+                Phase::Alternative(part, name),
+                Stats::from_samples(&samples),
+            ));
+        }
+    }
+
+    Ok(results)
+}
+
+pub fn print_header() {
+    println!(
+        "{:<5}{:<24}{:>12}{:>12}{:>12}{:>12}{:>10}  Change",
+        "Day", "Phase", "Min", "Median", "Mean", "Std dev", "Outliers"
+    );
+}
+
+pub fn print_stats(day: u8, phase: Phase, stats: &Stats, change: Option<Change>) {
+    println!(
+        "{:<5}{:<24}{:>12}{:>12}{:>12}{:>12}{:>10}  {}",
+        format!("{day:02}"),
+        phase.to_string(),
+        format!("{:.2?}", stats.min),
+        format!("{:.2?}", stats.median),
+        format!("{:.2?}", stats.mean),
+        format!("{:.2?}", stats.stddev),
+        format!("{}/{}", stats.outliers, stats.samples),
+        change.map_or_else(|| "new".to_string(), |change| change.to_string()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_code_faster_than_the_clock() {
+        let config = Config {
+            warmup: Duration::ZERO,
+            samples: 3,
+            sample_time: Duration::from_millis(1),
+        };
+        let result = sample(&config, || ());
+
+        assert_eq!(result.len(), 3);
+    }
+
+    #[test]
+    fn test_phase_key() {
+        assert_eq!(Phase::Parse.key(), "parse");
+        assert_eq!(Phase::Part(Part::Two).key(), "part_two");
+        assert_eq!(
+            Phase::Alternative(Part::One, "find_joltage").key(),
+            "part_one:find_joltage"
+        );
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = [3.0, 1.0, 2.0, 4.0, 5.0, 2.0, 3.0, 100.0];
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.samples, 8);
+        assert_eq!(stats.min, Duration::from_secs(1));
+        assert_eq!(stats.median, Duration::from_secs(3));
+        assert_eq!(stats.mean, Duration::from_secs_f64(15.0));
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    fn test_sample() {
+        let config = Config {
+            warmup: Duration::ZERO,
+            samples: 5,
+            sample_time: Duration::from_micros(10),
+        };
+        let samples = sample(&config, || (0..100u64).sum::<u64>());
+
+        assert_eq!(samples.len(), 5);
+        assert!(samples.iter().all(|&s| s >= 0.0));
+    }
+}
@@ -0,0 +1,645 @@
+use std::{path::PathBuf, time::Duration};
+
+use crate::{
+    Part, bench, differential,
+    input::Source,
+    runner::{self, Format},
+    scale,
+};
+
+pub const USAGE: &str = "\
+Usage: aoc2025 <day|all> [options]
+       aoc2025 bench <day|all> [options]
+       aoc2025 alloc <day|all> [options]
+       aoc2025 new <day>
+       aoc2025 watch <day>
+       aoc2025 fetch <day>
+       aoc2025 submit <day> <1|2>
+       aoc2025 extract <day> [--from <path>] [--block <n>] [--name <name>]
+       aoc2025 generate <day> [--size <n>] [--seed <n>]
+       aoc2025 scale <day> [scale options]
+
+Commands:
+  <day>          Run the given day
+  all            Run every registered day
+  bench          Benchmark the parse step, parts and alternative implementations
+                 of the parts of the selected days
+  alloc          Count the allocations, bytes allocated and peak live bytes
+                 of the parse step and parts of the selected days, when
+                 built with `--features count-alloc`
+  new <day>      Generate and register the module of a new day
+  watch <day>    Run the examples then the input of a day on every change
+                 to the sources, its input or examples
+  fetch <day>    Download the input of a day into the inputs directory,
+                 unless it is already there
+  submit <day> <part>
+                 Solve a part on the input and submit the answer, unless it
+                 is known to be wrong, then record the outcome in `answers`
+  extract <day>  Save a `<pre><code>` block of the puzzle page as an example,
+                 from a saved page or the page fetched like the input,
+                 asking which one when there are several
+  generate <day> Print a random input of the day, of about `size` lines
+                 [default: 100], the same for the same seed
+  scale <day>    Estimate the complexity of a day from generated inputs of
+                 doubling size
+
+Options:
+  -p, --part <1|2>         Only run the given part
+  -i, --input <path>       Read the input from a file, or stdin with `-`
+  -e, --example [<name>]   Run on the (named) example instead of the input
+  -f, --format <text|json> Output format, json prints one object per part
+  -j, --jobs <n>           Number of days to run in parallel [default: 1]
+  -t, --timeout <s>        Seconds after which a step is given up on [default: 15]
+  -v, --verbose            Show more diagnostics on stderr, repeat for more
+
+Bench options:
+  -n, --samples <n>        Number of samples per phase [default: 100]
+  -w, --warmup <ms>        Warm-up time per phase [default: 500]
+  -s, --save-baseline      Save the results as the baseline for later runs on
+                           the same input, which fail when significantly slower
+
+Scale options:
+  -p, --part <1|2>         Only time the given part, besides the parse step
+      --from <n>           Generator size of the first input [default: 10]
+      --steps <n>          Maximum number of sizes [default: 10]
+      --limit <s>          Stop once a phase takes longer [default: 1]
+      --seed <n>           Seed of the generated inputs [default: 2025]
+
+Environment:
+  AOC_INPUT_DIR  Directory with the `day_XX.txt` inputs, defaults to `inputs`
+  AOC_SESSION    Session cookie of the website, or write it to `.session`
+  AOC_BASE_URL   Plain HTTP address of a proxy on this machine adding TLS in
+                 front of the website, required to fetch and submit
+  AOC_LOG        Diagnostics to show, a level (error, warn, info, debug,
+                 trace) and per-day levels like `day05=debug`";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunArgs {
+    pub selection: Selection,
+    pub parts: Vec<Part>,
+    pub source: Source,
+    pub format: Format,
+    pub jobs: usize,
+    pub timeout: Duration,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(RunArgs),
+    Bench {
+        run: RunArgs,
+        config: bench::Config,
+        save_baseline: bool,
+    },
+    /// Count the allocations of the selected days, needs the `count-alloc` feature
+    Alloc(RunArgs),
+    New {
+        day: u8,
+    },
+    Watch {
+        day: u8,
+    },
+    Fetch {
+        day: u8,
+    },
+    Submit {
+        day: u8,
+        part: Part,
+    },
+    Extract {
+        day: u8,
+        /// Saved puzzle page, instead of the fetched one
+        from: Option<PathBuf>,
+        /// 1-based index of the block, instead of asking
+        block: Option<usize>,
+        name: Option<String>,
+    },
+    Generate {
+        day: u8,
+        size: usize,
+        seed: u64,
+    },
+    Scale {
+        day: u8,
+        parts: Vec<Part>,
+        config: scale::Config,
+    },
+}
+
+fn parse_day(arg: &str) -> Result<u8, String> {
+    match arg.parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!(
+            "invalid day {arg:?}, expected a number from 1 to 25"
+        )),
+    }
+}
+
+fn parse_part(arg: Option<String>) -> Result<Part, String> {
+    match arg.as_deref() {
+        Some("1") => Ok(Part::One),
+        Some("2") => Ok(Part::Two),
+        Some(other) => Err(format!("invalid part {other:?}, expected 1 or 2")),
+        None => Err("missing value for --part".to_string()),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, arg: Option<String>) -> Result<T, String> {
+    let arg = arg.ok_or_else(|| format!("missing value for {flag}"))?;
+    arg.parse()
+        .map_err(|_| format!("invalid value {arg:?} for {flag}"))
+}
+
+/// Flags followed by a value, which is never taken for a `-v` flag
+const VALUE_FLAGS: &[&str] = &[
+    "-p",
+    "--part",
+    "-i",
+    "--input",
+    "-f",
+    "--format",
+    "-j",
+    "--jobs",
+    "-t",
+    "--timeout",
+    "-n",
+    "--samples",
+    "-w",
+    "--warmup",
+    "--from",
+    "--steps",
+    "--limit",
+    "--seed",
+    "--block",
+    "--name",
+    "--size",
+];
+
+/// Number of `-v` flags in an argument, if it is one
+fn verbose_flags(arg: &str) -> Option<usize> {
+    match arg {
+        "--verbose" => Some(1),
+        flag => flag
+            .strip_prefix('-')
+            .filter(|vs| !vs.is_empty() && vs.bytes().all(|b| b == b'v'))
+            .map(str::len),
+    }
+}
+
+/// Remove the `-v` flags from the arguments, accepted with every command,
+/// returning how many times they were given
+pub fn take_verbosity(args: &mut Vec<String>) -> u8 {
+    let mut verbosity = 0u8;
+    let mut value = false;
+
+    args.retain(|arg| {
+        if value {
+            value = false;
+            return true;
+        }
+        value = VALUE_FLAGS.contains(&arg.as_str());
+
+        match verbose_flags(arg) {
+            Some(count) => {
+                verbosity = verbosity.saturating_add(count as u8);
+                false
+            }
+            None => true,
+        }
+    });
+
+    verbosity
+}
+
+/// Parse the command line arguments, without the program name
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter().peekable();
+
+    let mut first = args.next();
+
+    // Measuring commands take the selection and the options of a run
+    let benching = first.as_deref() == Some("bench");
+    let counting = first.as_deref() == Some("alloc");
+    if benching || counting {
+        first = args.next();
+    }
+    let running = !benching && !counting;
+
+    let selection = match first.as_deref() {
+        Some(command @ ("new" | "watch" | "fetch")) if running => {
+            let day = parse_day(&args.next().ok_or("missing day")?)?;
+
+            return match (args.next(), command) {
+                (Some(other), _) => Err(format!("unexpected argument {other:?}")),
+                (None, "new") => Ok(Command::New { day }),
+                (None, "watch") => Ok(Command::Watch { day }),
+                (None, _) => Ok(Command::Fetch { day }),
+            };
+        }
+        Some("submit") if running => {
+            let day = parse_day(&args.next().ok_or("missing day")?)?;
+            let part = parse_part(args.next())?;
+
+            return match args.next() {
+                Some(other) => Err(format!("unexpected argument {other:?}")),
+                None => Ok(Command::Submit { day, part }),
+            };
+        }
+        Some("extract") if running => {
+            let day = parse_day(&args.next().ok_or("missing day")?)?;
+            let (mut from, mut block, mut name) = (None, None, None);
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--from" => {
+                        from = Some(PathBuf::from(
+                            args.next().ok_or("missing value for --from")?,
+                        ))
+                    }
+                    "--block" => {
+                        block = Some(parse_number(&arg, args.next())?).filter(|&n| n > 0);
+                        if block.is_none() {
+                            return Err("--block must be at least 1".to_string());
+                        }
+                    }
+                    "--name" => name = Some(args.next().ok_or("missing value for --name")?),
+                    other => return Err(format!("unexpected argument {other:?}")),
+                }
+            }
+
+            return Ok(Command::Extract {
+                day,
+                from,
+                block,
+                name,
+            });
+        }
+        Some("generate") if running => {
+            let day = parse_day(&args.next().ok_or("missing day")?)?;
+            let mut size = 100;
+            let mut seed = differential::DEFAULT_SEED;
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--size" => size = parse_number(&arg, args.next())?,
+                    "--seed" => seed = parse_number(&arg, args.next())?,
+                    other => return Err(format!("unexpected argument {other:?}")),
+                }
+            }
+
+            return Ok(Command::Generate { day, size, seed });
+        }
+        Some("scale") if running => {
+            let day = parse_day(&args.next().ok_or("missing day")?)?;
+            let mut parts = Part::BOTH.to_vec();
+            let mut config = scale::Config::default();
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "-p" | "--part" => parts = vec![parse_part(args.next())?],
+                    "--from" => config.start = parse_number(&arg, args.next())?,
+                    "--steps" => config.steps = parse_number(&arg, args.next())?,
+                    "--limit" => {
+                        let secs: f64 = parse_number(&arg, args.next())?;
+                        config.limit = Duration::try_from_secs_f64(secs)
+                            .map_err(|_| format!("invalid limit {secs}"))?;
+                    }
+                    "--seed" => config.seed = parse_number(&arg, args.next())?,
+                    other => return Err(format!("unexpected argument {other:?}")),
+                }
+            }
+
+            if config.start == 0 {
+                return Err("--from must be at least 1".to_string());
+            }
+            if config.steps == 0 {
+                return Err("--steps must be at least 1".to_string());
+            }
+
+            // The size doubles at every step, the last one must still fit
+            let last = (config.steps - 1) as u32;
+            if last >= usize::BITS || (config.start << last) >> last != config.start {
+                return Err(format!(
+                    "--from {} doubled {last} times is too large",
+                    config.start
+                ));
+            }
+
+            return Ok(Command::Scale { day, parts, config });
+        }
+        Some("all") => Selection::All,
+        Some(day) => Selection::Day(parse_day(day)?),
+        None => return Err("missing day".to_string()),
+    };
+
+    let mut config = bench::Config::default();
+    let mut save_baseline = false;
+    let mut run = RunArgs {
+        selection,
+        parts: Part::BOTH.to_vec(),
+        source: Source::default(),
+        format: Format::default(),
+        jobs: 1,
+        timeout: runner::DEFAULT_TIMEOUT,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-p" | "--part" => run.parts = vec![parse_part(args.next())?],
+            "-i" | "--input" => {
+                let path = args.next().ok_or("missing value for --input")?;
+                run.source = Source::from_arg(&path);
+            }
+            "-e" | "--example" => {
+                let name = args.next_if(|next| !next.starts_with('-'));
+                run.source = Source::Example(name);
+            }
+            "-f" | "--format" if running => {
+                run.format = match args.next().as_deref() {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some(other) => return Err(format!("invalid format {other:?}")),
+                    None => return Err("missing value for --format".to_string()),
+                }
+            }
+            "-j" | "--jobs" if running => {
+                run.jobs = parse_number(&arg, args.next())?;
+                if run.jobs == 0 {
+                    return Err("--jobs must be at least 1".to_string());
+                }
+            }
+            "-t" | "--timeout" if running => {
+                let secs: f64 = parse_number(&arg, args.next())?;
+                run.timeout = Duration::try_from_secs_f64(secs)
+                    .ok()
+                    .filter(|timeout| !timeout.is_zero())
+                    .ok_or_else(|| format!("invalid timeout {secs}"))?;
+            }
+            "-n" | "--samples" if benching => {
+                config.samples = parse_number(&arg, args.next())?;
+                if config.samples == 0 {
+                    return Err("--samples must be at least 1".to_string());
+                }
+            }
+            "-w" | "--warmup" if benching => {
+                config.warmup = Duration::from_millis(parse_number(&arg, args.next())?);
+            }
+            "-s" | "--save-baseline" if benching => save_baseline = true,
+            other => return Err(format!("unexpected argument {other:?}")),
+        }
+    }
+
+    if run.selection == Selection::All && matches!(run.source, Source::File(_) | Source::Stdin) {
+        return Err("--input can only be used with a single day".to_string());
+    }
+
+    if benching {
+        Ok(Command::Bench {
+            run,
+            config,
+            save_baseline,
+        })
+    } else if counting {
+        Ok(Command::Alloc(run))
+    } else {
+        Ok(Command::Run(run))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_day_and_part() {
+        let expected = Command::Run(RunArgs {
+            selection: Selection::Day(5),
+            parts: vec![Part::Two],
+            source: Source::Puzzle,
+            format: Format::Text,
+            jobs: 1,
+            timeout: runner::DEFAULT_TIMEOUT,
+        });
+        let result = parse(args("5 --part 2"));
+
+        assert_eq!(result, Ok(expected))
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let expected = Command::Run(RunArgs {
+            selection: Selection::All,
+            parts: vec![Part::One, Part::Two],
+            source: Source::Example(None),
+            format: Format::Json,
+            jobs: 4,
+            timeout: Duration::from_millis(500),
+        });
+        let result = parse(args("all --example --format json --jobs 4 -t 0.5"));
+
+        assert_eq!(result, Ok(expected))
+    }
+
+    #[test]
+    fn test_parse_source() {
+        let source = |s| match parse(args(s)) {
+            Ok(Command::Run(run)) => run.source,
+            other => panic!("unexpected {other:?}"),
+        };
+
+        assert_eq!(source("1 -i -"), Source::Stdin);
+        assert_eq!(
+            source("1 --input a.txt"),
+            Source::File(PathBuf::from("a.txt"))
+        );
+        assert_eq!(
+            source("1 -e edge -p 1"),
+            Source::Example(Some("edge".to_string()))
+        );
+        assert_eq!(source("1 -e -p 1"), Source::Example(None));
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let expected = Command::Bench {
+            run: RunArgs {
+                selection: Selection::Day(3),
+                parts: vec![Part::One],
+                source: Source::Puzzle,
+                format: Format::Text,
+                jobs: 1,
+                timeout: runner::DEFAULT_TIMEOUT,
+            },
+            config: bench::Config {
+                samples: 20,
+                warmup: Duration::from_millis(50),
+                ..Default::default()
+            },
+            save_baseline: true,
+        };
+        let result = parse(args("bench 3 -p 1 --samples 20 -w 50 --save-baseline"));
+
+        assert_eq!(result, Ok(expected));
+        assert!(parse(args("3 --samples 20")).is_err());
+        assert!(parse(args("bench 3 --samples 0")).is_err());
+        assert!(parse(args("bench new 3")).is_err());
+    }
+
+    #[test]
+    fn test_parse_alloc() {
+        let expected = Command::Alloc(RunArgs {
+            selection: Selection::All,
+            parts: vec![Part::Two],
+            source: Source::Example(None),
+            format: Format::Text,
+            jobs: 1,
+            timeout: runner::DEFAULT_TIMEOUT,
+        });
+        let result = parse(args("alloc all -e -p 2"));
+
+        assert_eq!(result, Ok(expected));
+        assert!(parse(args("alloc 3 --samples 20")).is_err());
+        assert!(parse(args("alloc 3 --jobs 2")).is_err());
+        assert!(parse(args("alloc new 3")).is_err());
+    }
+
+    #[test]
+    fn test_parse_new() {
+        assert_eq!(parse(args("new 9")), Ok(Command::New { day: 9 }));
+        assert!(parse(args("new")).is_err());
+        assert!(parse(args("new 9 --part 1")).is_err());
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        assert_eq!(parse(args("fetch 9")), Ok(Command::Fetch { day: 9 }));
+        assert!(parse(args("fetch 26")).is_err());
+        assert!(parse(args("bench fetch 9")).is_err());
+    }
+
+    #[test]
+    fn test_parse_submit() {
+        assert_eq!(
+            parse(args("submit 9 2")),
+            Ok(Command::Submit {
+                day: 9,
+                part: Part::Two
+            })
+        );
+        assert!(parse(args("submit 9")).is_err());
+        assert!(parse(args("submit 9 3")).is_err());
+        assert!(parse(args("submit 9 1 2")).is_err());
+    }
+
+    #[test]
+    fn test_parse_extract() {
+        assert_eq!(
+            parse(args("extract 6 --from page.html --block 2 --name page")),
+            Ok(Command::Extract {
+                day: 6,
+                from: Some(PathBuf::from("page.html")),
+                block: Some(2),
+                name: Some("page".to_string())
+            })
+        );
+        assert_eq!(
+            parse(args("extract 6")),
+            Ok(Command::Extract {
+                day: 6,
+                from: None,
+                block: None,
+                name: None
+            })
+        );
+        assert!(parse(args("extract 6 --block 0")).is_err());
+        assert!(parse(args("extract 6 --from")).is_err());
+    }
+
+    #[test]
+    fn test_parse_generate() {
+        assert_eq!(
+            parse(args("generate 4 --seed 7 --size 20")),
+            Ok(Command::Generate {
+                day: 4,
+                size: 20,
+                seed: 7
+            })
+        );
+        assert_eq!(
+            parse(args("generate 4")),
+            Ok(Command::Generate {
+                day: 4,
+                size: 100,
+                seed: differential::DEFAULT_SEED
+            })
+        );
+        assert!(parse(args("generate 4 --part 1")).is_err());
+    }
+
+    #[test]
+    fn test_parse_scale() {
+        let expected = Command::Scale {
+            day: 8,
+            parts: vec![Part::Two],
+            config: scale::Config {
+                start: 50,
+                limit: Duration::from_millis(250),
+                ..Default::default()
+            },
+        };
+        let result = parse(args("scale 8 -p 2 --from 50 --limit 0.25"));
+
+        assert_eq!(result, Ok(expected));
+        assert!(parse(args("scale 8 --from 0")).is_err());
+        assert!(parse(args("scale 8 --steps 0")).is_err());
+        assert!(parse(args("scale 8 --steps 100")).is_err());
+        assert!(parse(args("scale 8 --from 3 --steps 64")).is_err());
+        assert!(parse(args("scale 8 --from 1 --steps 64")).is_ok());
+        assert!(parse(args("scale 8 --jobs 2")).is_err());
+    }
+
+    #[test]
+    fn test_parse_watch() {
+        assert_eq!(parse(args("watch 9")), Ok(Command::Watch { day: 9 }));
+        assert!(parse(args("watch")).is_err());
+        assert!(parse(args("bench watch 9")).is_err());
+    }
+
+    #[test]
+    fn test_take_verbosity() {
+        let mut result = args("5 -v --part 2 -vv --verbose");
+
+        assert_eq!(take_verbosity(&mut result), 4);
+        assert_eq!(result, args("5 --part 2"));
+        assert_eq!(take_verbosity(&mut args("5 -")), 0);
+
+        let mut result = args("extract 5 --name -vv -v");
+        assert_eq!(take_verbosity(&mut result), 1);
+        assert_eq!(result, args("extract 5 --name -vv"));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse(args("")).is_err());
+        assert!(parse(args("26")).is_err());
+        assert!(parse(args("3 --part 3")).is_err());
+        assert!(parse(args("3 --part")).is_err());
+        assert!(parse(args("3 --input")).is_err());
+        assert!(parse(args("all --input -")).is_err());
+        assert!(parse(args("all --format yaml")).is_err());
+        assert!(parse(args("bench all --format json")).is_err());
+        assert!(parse(args("all --jobs 0")).is_err());
+        assert!(parse(args("all --timeout 0")).is_err());
+        assert!(parse(args("all --timeout -1")).is_err());
+    }
+}
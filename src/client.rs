@@ -0,0 +1,501 @@
+//! Minimal HTTP client for the puzzle website, and the input fetcher
+//!
+//! Plain HTTP over `std::net` keeps the crate free of dependencies. The site
+//! itself only speaks HTTPS, so `AOC_BASE_URL` must point at a local proxy
+//! that adds TLS, or at a stand-in server in tests. The session cookie is
+//! never sent anywhere else, as it would cross the network in clear.
+
+use std::{
+    env,
+    fs::{self, File},
+    io::{Read, Write},
+    net::{IpAddr, TcpStream},
+    path::{Path, PathBuf},
+    str,
+    time::Duration,
+};
+
+use crate::input::{Dirs, Source};
+
+/// Environment variable with the session cookie of the logged in user
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable with the base URL of the puzzle website, through a
+/// local proxy adding TLS
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const YEAR: u16 = 2025;
+
+/// Name of the file at the crate root holding the session cookie, when it is
+/// not set in the environment
+pub const SESSION_FILE: &str = ".session";
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Where the website is, and who to talk to it as
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub base_url: Option<String>,
+    pub session: Option<String>,
+}
+
+impl Config {
+    /// Config from `AOC_BASE_URL`, and `AOC_SESSION` or the `.session` file
+    pub fn from_env() -> Self {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let session = env::var(SESSION_VAR)
+            .ok()
+            .or_else(|| fs::read_to_string(root.join(SESSION_FILE)).ok())
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty());
+
+        Config {
+            base_url: env::var(BASE_URL_VAR).ok().filter(|url| !url.is_empty()),
+            session,
+        }
+    }
+
+    fn base_url(&self) -> Result<&str, String> {
+        self.base_url
+            .as_deref()
+            .ok_or_else(|| format!("no base URL, set {BASE_URL_VAR} to a local proxy adding TLS"))
+    }
+
+    fn session(&self) -> Result<&str, String> {
+        self.session.as_deref().ok_or_else(|| {
+            format!("no session token, set {SESSION_VAR} or write it to {SESSION_FILE}")
+        })
+    }
+}
+
+/// `http://host[:port][/prefix]`, split for the request
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Url<'a> {
+    host: &'a str,
+    port: u16,
+    prefix: &'a str,
+}
+
+impl<'a> Url<'a> {
+    fn parse(url: &'a str) -> Result<Self, String> {
+        let rest = url.strip_prefix("http://").ok_or_else(|| {
+            format!("unsupported base URL {url:?}, only plain http:// URLs are supported")
+        })?;
+        let (authority, prefix) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+
+        let (host, port) = match authority.split_once(':') {
+            Some((host, port)) => (
+                host,
+                port.parse()
+                    .map_err(|_| format!("invalid port in base URL {url:?}"))?,
+            ),
+            None => (authority, 80),
+        };
+
+        if host.is_empty() {
+            return Err(format!("missing host in base URL {url:?}"));
+        }
+
+        Ok(Url {
+            host,
+            port,
+            prefix: prefix.trim_end_matches('/'),
+        })
+    }
+
+    /// Value of the `Host` header, which has the port unless it is the default
+    fn authority(&self) -> String {
+        if self.port == 80 {
+            self.host.to_string()
+        } else {
+            format!("{}:{}", self.host, self.port)
+        }
+    }
+
+    /// Whether the host is this machine, so plain HTTP stays off the network
+    fn is_loopback(&self) -> bool {
+        self.host.eq_ignore_ascii_case("localhost")
+            || self.host.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Split `bytes` around the first occurrence of `separator`
+fn split_once<'a>(bytes: &'a [u8], separator: &[u8]) -> Option<(&'a [u8], &'a [u8])> {
+    let i = bytes
+        .windows(separator.len())
+        .position(|window| window == separator)?;
+    Some((&bytes[..i], &bytes[i + separator.len()..]))
+}
+
+/// Decode a chunked transfer encoded body, whose chunk sizes are in bytes
+fn dechunk(mut body: &[u8]) -> Option<Vec<u8>> {
+    let mut decoded = Vec::new();
+
+    loop {
+        let (size, rest) = split_once(body, b"\r\n")?;
+        let size = str::from_utf8(size).ok()?.split(';').next()?.trim();
+        let size = usize::from_str_radix(size, 16).ok()?;
+        if size == 0 {
+            return Some(decoded);
+        }
+
+        decoded.extend_from_slice(rest.get(..size)?);
+        body = rest.get(size..)?.strip_prefix(b"\r\n")?;
+    }
+}
+
+/// Parse a raw response, decoding the body as text only once it is whole
+fn parse_response(raw: &[u8]) -> Result<Response, String> {
+    let (head, body) =
+        split_once(raw, b"\r\n\r\n").ok_or("malformed HTTP response, no end of headers")?;
+    let head = String::from_utf8_lossy(head);
+    let mut lines = head.lines();
+
+    let status = lines
+        .next()
+        .and_then(|line| line.split(' ').nth(1))
+        .and_then(|status| status.parse().ok())
+        .ok_or("malformed HTTP response, invalid status line")?;
+
+    let chunked = lines.any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.eq_ignore_ascii_case("transfer-encoding") && value.trim() == "chunked"
+        })
+    });
+
+    let body = if chunked {
+        String::from_utf8_lossy(&dechunk(body).ok_or("malformed chunked HTTP response")?)
+            .into_owned()
+    } else {
+        String::from_utf8_lossy(body).into_owned()
+    };
+
+    Ok(Response { status, body })
+}
+
+/// Send a request to `path` under the base URL, with the session cookie, and
+/// a form encoded `form` body for a POST
+pub fn request(config: &Config, path: &str, form: Option<&str>) -> Result<Response, String> {
+    let base_url = config.base_url()?;
+    let url = Url::parse(base_url)?;
+    if !url.is_loopback() {
+        return Err(format!(
+            "refusing to send the session cookie over plain HTTP to {}, \
+             {BASE_URL_VAR} must point at a local proxy",
+            url.host
+        ));
+    }
+    let session = config.session()?;
+    let method = if form.is_some() { "POST" } else { "GET" };
+
+    let mut request = format!(
+        "{method} {}{path} HTTP/1.1\r\n\
+         Host: {}\r\n\
+         Cookie: session={session}\r\n\
+         User-Agent: aoc2025 runner\r\n\
+         Connection: close\r\n",
+        url.prefix,
+        url.authority()
+    );
+    if let Some(form) = form {
+        request += &format!(
+            "Content-Type: application/x-www-form-urlencoded\r\n\
+             Content-Length: {}\r\n\r\n{form}",
+            form.len()
+        );
+    } else {
+        request += "\r\n";
+    }
+
+    let error = |err| format!("request to {base_url}{path} failed: {err}");
+
+    let mut stream = TcpStream::connect((url.host, url.port)).map_err(error)?;
+    stream.set_read_timeout(Some(TIMEOUT)).map_err(error)?;
+    stream.write_all(request.as_bytes()).map_err(error)?;
+
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw).map_err(error)?;
+
+    parse_response(&raw)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Create a file only the current user can read, as inputs must not be shared
+fn create_private(path: &Path) -> std::io::Result<File> {
+    let mut options = File::options();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    options.open(path)
+}
+
+/// Download `url_path` to `path`, in the inputs directory, unless it is
+/// already there
+fn download(
+    config: &Config,
+    url_path: &str,
+    path: PathBuf,
+    dirs: &Dirs,
+) -> Result<Fetched, String> {
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let response = request(config, url_path, None)?;
+    match response.status {
+        200 => {}
+        404 => return Err(format!("{url_path} is not available yet")),
+        400 | 500 => {
+            return Err(format!(
+                "the website rejected the request ({}), check the session token",
+                response.status
+            ));
+        }
+        status => {
+            let reason = response.body.lines().next().unwrap_or_default();
+            return Err(format!("unexpected response {status} {reason}"));
+        }
+    }
+
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+
+    builder
+        .create(&dirs.inputs)
+        .map_err(|err| format!("failed to create {}: {err}", dirs.inputs.display()))?;
+
+    // Written aside then moved, so an interrupted download is never cached
+    let mut partial = path.clone().into_os_string();
+    partial.push(".part");
+    create_private(Path::new(&partial))
+        .and_then(|mut file| file.write_all(response.body.as_bytes()))
+        .and_then(|_| fs::rename(&partial, &path))
+        .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+/// Download the input of a day into the inputs directory, unless it is
+/// already there
+pub fn fetch(config: &Config, day: u8, dirs: &Dirs) -> Result<Fetched, String> {
+    let path = Source::Puzzle
+        .path(day, dirs)
+        .expect("puzzle inputs are files");
+
+    download(config, &format!("/{YEAR}/day/{day}/input"), path, dirs)
+}
+
+/// Path of the cached puzzle page of a day, next to its input
+pub fn page_path(day: u8, dirs: &Dirs) -> PathBuf {
+    dirs.inputs.join(format!("day_{day:02}.html"))
+}
+
+/// Download the puzzle page of a day next to its input, unless it is already
+/// there; delete it to see the second part once the first one is solved
+pub fn fetch_page(config: &Config, day: u8, dirs: &Dirs) -> Result<Fetched, String> {
+    download(
+        config,
+        &format!("/{YEAR}/day/{day}"),
+        page_path(day, dirs),
+        dirs,
+    )
+}
+
+/// Stand-in for the website, answering canned responses
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serve one connection per response, returning the base URL and the
+    /// requests received, with their body
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+
+                    let mut request = String::new();
+                    let mut length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some(value) = line.strip_prefix("Content-Length: ") {
+                            length = value.trim().parse().unwrap();
+                        }
+                        request += &line;
+                        if line == "\r\n" {
+                            break;
+                        }
+                    }
+                    let mut body_bytes = vec![0; length];
+                    reader.read_exact(&mut body_bytes).unwrap();
+                    request += &String::from_utf8(body_bytes).unwrap();
+
+                    write!(
+                        reader.get_mut(),
+                        "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                    request
+                })
+                .collect()
+        });
+
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::scratch_path;
+
+    fn config(base_url: String) -> Config {
+        Config {
+            base_url: Some(base_url),
+            session: Some("53cr37".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_url() {
+        let expected = Url {
+            host: "localhost",
+            port: 8080,
+            prefix: "/aoc",
+        };
+
+        assert_eq!(Url::parse("http://localhost:8080/aoc/"), Ok(expected));
+        assert_eq!(Url::parse("http://example.com").unwrap().port, 80);
+        assert_eq!(
+            Url::parse("http://localhost").unwrap().authority(),
+            "localhost"
+        );
+        assert_eq!(
+            Url::parse("http://localhost:8080/aoc").unwrap().authority(),
+            "localhost:8080"
+        );
+        assert!(Url::parse("https://adventofcode.com").is_err());
+        assert!(Url::parse("http://:80").is_err());
+    }
+
+    #[test]
+    fn test_request_stays_on_this_machine() {
+        let remote =
+            request(&config("http://adventofcode.com".to_string()), "/", None).unwrap_err();
+        let missing = request(
+            &Config {
+                base_url: None,
+                ..config(String::new())
+            },
+            "/",
+            None,
+        );
+
+        assert!(remote.contains("refusing"), "{remote}");
+        assert!(missing.unwrap_err().contains(BASE_URL_VAR));
+        assert!(Url::parse("http://127.0.0.1:3000").unwrap().is_loopback());
+        assert!(Url::parse("http://LocalHost").unwrap().is_loopback());
+        assert!(!Url::parse("http://10.0.0.1").unwrap().is_loopback());
+    }
+
+    #[test]
+    fn test_chunked_response() {
+        let raw = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nL68\n\r\n3\r\nR48\r\n0\r\n\r\n";
+        let result = parse_response(raw).unwrap();
+
+        assert_eq!(result.status, 200);
+        assert_eq!(result.body, "L68\nR48");
+
+        // A chunk boundary in the middle of a character
+        let raw = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n2\r\na\xc3\r\n1\r\n\xa9\r\n0\r\n\r\n";
+        assert_eq!(parse_response(raw).unwrap().body, "a\u{e9}");
+    }
+
+    #[test]
+    fn test_fetch_caches_input() {
+        let dir = scratch_path("fetch");
+        let dirs = Dirs::under(&dir);
+
+        let (base_url, server) = mock::serve(vec![(200, "L68\nR48\n")]);
+        let host = format!("Host: {}\r\n", &base_url["http://".len()..]);
+        let config = config(base_url);
+        let path = dirs.inputs.join("day_01.txt");
+
+        let first = fetch(&config, 1, &dirs);
+        // The server is gone, a second download would fail
+        let requests = server.join().unwrap();
+        let second = fetch(&config, 1, &dirs);
+        let content = fs::read_to_string(&path);
+        #[cfg(unix)]
+        let mode = {
+            use std::os::unix::fs::PermissionsExt;
+            fs::metadata(&path).unwrap().permissions().mode() & 0o777
+        };
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(first, Ok(Fetched::Downloaded(path.clone())));
+        assert_eq!(second, Ok(Fetched::Cached(path)));
+        assert_eq!(content.unwrap(), "L68\nR48\n");
+        assert!(requests[0].starts_with("GET /2025/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains(&host), "{}", requests[0]);
+        assert!(requests[0].contains("Cookie: session=53cr37\r\n"));
+        #[cfg(unix)]
+        assert_eq!(mode, 0o600);
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let dirs = Dirs::under(&scratch_path("fetch-errors"));
+
+        let (base_url, server) = mock::serve(vec![
+            (404, "Not Found"),
+            (400, "Puzzle inputs differ by user."),
+        ]);
+        let config = config(base_url);
+        let not_yet = fetch(&config, 12, &dirs).unwrap_err();
+        let rejected = fetch(&config, 12, &dirs).unwrap_err();
+        server.join().unwrap();
+        let without_session = fetch(
+            &Config {
+                session: None,
+                ..config
+            },
+            12,
+            &dirs,
+        );
+
+        assert!(
+            not_yet.contains("/2025/day/12/input is not available yet"),
+            "{not_yet}"
+        );
+        assert!(rejected.contains("session token"), "{rejected}");
+        assert!(without_session.unwrap_err().contains(SESSION_VAR));
+        assert!(!dirs.inputs.join("day_12.txt").exists());
+    }
+}
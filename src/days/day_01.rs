@@ -0,0 +1,91 @@
+use crate::{Answer, ParseError, ParseResult, Solution, parse::number, rng::Rng};
+
+pub struct Day01;
+
+fn parse_sign_step(input: &str, line: &str) -> ParseResult<(i32, i32)> {
+    let Some(dir) = line.get(..1) else {
+        return Err(ParseError::at(
+            Day01::DAY,
+            input,
+            line,
+            "expected a rotation like L68",
+        ));
+    };
+
+    let sign = match dir {
+        "R" => 1,
+        "L" => -1,
+        _ => {
+            return Err(ParseError::at(
+                Day01::DAY,
+                input,
+                dir,
+                format!("unknown direction {dir:?}, expected L or R"),
+            ));
+        }
+    };
+
+    let step = number(Day01::DAY, input, &line[1..])?;
+
+    Ok((sign, step))
+}
+
+/// Rotations of up to a thousand clicks in either direction
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let dir = rng.choose(&['L', 'R']);
+            format!("{dir}{}", rng.range(1..=999))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<(i32, i32)>;
+
+    const GENERATOR: Option<fn(&mut Rng, usize) -> String> = Some(generate);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input
+            .lines()
+            .map(|line| parse_sign_step(input, line))
+            .collect()
+    }
+
+    fn part_one(rotations: &Self::Input) -> Answer {
+        let (_, password): (i32, i32) =
+            rotations.iter().fold((50, 0), |(dial, acc), (sign, step)| {
+                let new = dial + sign * step;
+                let new = new.rem_euclid(100);
+
+                let pass = if new == 0 { 1 } else { 0 };
+
+                (new, acc + pass)
+            });
+
+        password.into()
+    }
+
+    fn part_two(rotations: &Self::Input) -> Answer {
+        let (_, password): (i32, i32) =
+            rotations.iter().fold((50, 0), |(dial, acc), (sign, step)| {
+                let new = dial + sign * step;
+
+                // Get number of total crossings
+                let mut pass = (new / 100).abs();
+
+                if dial != 0 && dial.signum() != new.signum() {
+                    pass += 1
+                }
+
+                crate::trace!("{dial:3} -> {new:4} -> {:3} => {pass}", new.rem_euclid(100));
+
+                (new.rem_euclid(100), acc + pass)
+            });
+
+        password.into()
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+
+use crate::{Answer, ParseError, ParseResult, Solution, num, parse::number, rng::Rng};
+
+pub struct Day02;
+
+fn check_from_lower(
+    lower: &str,
+    smaller: u64,
+    bigger: u64,
+    set: &mut HashSet<u64>,
+    use_half_len: bool,
+) {
+    let len = lower.len();
+    let divisors = if use_half_len {
+        if !len.is_multiple_of(2) {
+            return;
+        }
+        vec![len / 2]
+    } else {
+        num::proper_divisors(len)
+    };
+
+    for d in divisors {
+        let n = len / d;
+        let (first, _) = lower.split_at(d);
+
+        let rep = first.repeat(n);
+        let mut a: u64 = rep.parse().unwrap();
+        let mut running: u64 = first.parse().unwrap();
+
+        while a <= bigger {
+            if a >= smaller {
+                set.insert(a);
+            }
+
+            running += 1;
+            a = running.to_string().repeat(n).parse().unwrap();
+        }
+    }
+}
+
+fn check_from_upper(
+    upper: &str,
+    smaller: u64,
+    bigger: u64,
+    set: &mut HashSet<u64>,
+    use_half_len: bool,
+) {
+    let len = upper.len();
+    let divisors = if use_half_len {
+        if !len.is_multiple_of(2) {
+            return;
+        }
+        vec![len / 2]
+    } else {
+        num::proper_divisors(len)
+    };
+
+    for d in divisors {
+        let n = len / d;
+        let (_, last) = upper.split_at(len - d);
+
+        let rep = last.repeat(n);
+        let mut a: u64 = rep.parse().unwrap();
+        let mut running: u64 = last.parse().unwrap();
+
+        while a >= smaller {
+            if a <= bigger {
+                set.insert(a);
+            }
+
+            running -= 1;
+            a = running.to_string().repeat(n).parse().unwrap();
+        }
+    }
+}
+
+fn sum_invalid_ids(ranges: &[(u64, u64)], use_half_len: bool) -> u64 {
+    ranges.iter().fold(0, |acc, &(smaller, bigger)| {
+        let lower = smaller.to_string();
+        let upper = bigger.to_string();
+
+        let mut set = HashSet::new();
+
+        check_from_lower(&lower, smaller, bigger, &mut set, use_half_len);
+        check_from_upper(&upper, smaller, bigger, &mut set, use_half_len);
+
+        acc + set.iter().sum::<u64>()
+    })
+}
+
+/// Ranges of IDs of up to ten digits, whose bounds differ by at most one digit
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let digits = rng.range(1..=10) as u32;
+            let lower = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+            let upper = lower + rng.range(0..=lower / 1000 + 100);
+            format!("{lower}-{upper}")
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(u64, u64)>;
+
+    const GENERATOR: Option<fn(&mut Rng, usize) -> String> = Some(generate);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input
+            .split(',')
+            .map(|range| {
+                let (lower, upper) = range.split_once('-').ok_or_else(|| {
+                    ParseError::at(Self::DAY, input, range, "expected a range like 11-22")
+                })?;
+
+                Ok((
+                    number(Self::DAY, input, lower)?,
+                    number(Self::DAY, input, upper)?,
+                ))
+            })
+            .collect()
+    }
+
+    fn part_one(ranges: &Self::Input) -> Answer {
+        sum_invalid_ids(ranges, true).into()
+    }
+
+    fn part_two(ranges: &Self::Input) -> Answer {
+        sum_invalid_ids(ranges, false).into()
+    }
+}
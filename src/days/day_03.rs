@@ -0,0 +1,140 @@
+use std::collections::HashSet;
+
+use crate::{Alternative, Answer, ParseError, ParseResult, Part, Solution, rng::Rng};
+
+pub struct Day03;
+
+/// Recursive implementation, initially implemented when solving part one
+fn find_joltage_recursive(index: usize, bank: &[u8], already_seen: &mut HashSet<u8>) -> u64 {
+    if (index + 1) == bank.len() {
+        return 0;
+    }
+
+    let first = *bank.get(index).unwrap();
+    let mut second = 0;
+    let mut joltages = vec![];
+
+    for i in index + 1..bank.len() {
+        let battery = bank[i];
+
+        if battery > first && already_seen.get(&battery).is_none() {
+            already_seen.insert(battery);
+
+            let joltage = find_joltage_recursive(i, bank, already_seen);
+            joltages.push(joltage);
+        }
+
+        if battery > second {
+            second = battery;
+        }
+    }
+
+    let joltage = (10 * first + second) as u64;
+    joltages.push(joltage);
+    joltages.into_iter().max().unwrap()
+}
+
+// Implemented for part two, but actually also works for part one
+// and it's easier to understand
+fn find_joltage(bank: &[u8], n: usize) -> u64 {
+    let mut joltage = 0;
+    let mut idx = 0;
+
+    for i in 0..n {
+        let mut biggest = 0;
+
+        #[allow(clippy::mut_range_bound)]
+        for j in idx..bank.len() {
+            if j + n - 1 - i == bank.len() {
+                break;
+            }
+
+            let battery = bank[j];
+
+            if battery > biggest {
+                biggest = battery;
+                // This should be fine given the first
+                // if condition in the inner loop
+                idx = j + 1;
+            }
+        }
+
+        joltage += biggest as u64 * 10u64.pow((n - 1 - i) as u32);
+    }
+
+    joltage
+}
+
+/// Banks of 12 to 30 batteries rated 1 to 9, like the puzzle input
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..rng.range(12..=30))
+                .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<u8>>;
+
+    const ALTERNATIVES: &'static [Alternative<Self::Input>] = &[Alternative {
+        name: "find_joltage",
+        part: Part::One,
+        solve: |banks| {
+            banks
+                .iter()
+                .map(|bank| find_joltage(bank, 2))
+                .sum::<u64>()
+                .into()
+        },
+    }];
+
+    const GENERATOR: Option<fn(&mut Rng, usize) -> String> = Some(generate);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input
+            .lines()
+            .map(|bank| {
+                // Convert chars to u8
+                bank.char_indices()
+                    .map(|(i, c)| match c.to_digit(10) {
+                        Some(joltage) => Ok(joltage as u8),
+                        None => Err(ParseError::at(
+                            Self::DAY,
+                            input,
+                            &bank[i..i + c.len_utf8()],
+                            format!("invalid battery {c:?}, expected a digit"),
+                        )),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part_one(banks: &Self::Input) -> Answer {
+        let joltage = banks.iter().fold(0, |acc, batteries| {
+            // Keep track of already seen battery joltages,
+            // so we avoid doing the same work multiple times
+            let mut seen = HashSet::new();
+
+            let joltage = find_joltage_recursive(0, batteries, &mut seen);
+            acc + joltage
+        });
+
+        joltage.into()
+    }
+
+    fn part_two(banks: &Self::Input) -> Answer {
+        let joltage = banks.iter().fold(0, |acc, batteries| {
+            let joltage = find_joltage(batteries, 12);
+            acc + joltage
+        });
+
+        joltage.into()
+    }
+}
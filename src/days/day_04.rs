@@ -0,0 +1,71 @@
+use crate::{Answer, ParseResult, Solution, grid::Grid, parse, rng::Rng};
+
+pub struct Day04;
+
+/// Whether a forklift can reach the roll of paper at `pos`, which needs
+/// fewer than 4 rolls in the 8 cells around it
+fn accessible(grid: &Grid<char>, pos: (usize, usize)) -> bool {
+    let (row, col) = pos;
+
+    grid[pos] == '@'
+        && grid
+            .neighbours(row, col)
+            .filter(|&neighbour| grid[neighbour] == '@')
+            .count()
+            < 4
+}
+
+/// Square grid of `size` rows, with paper rolls on about 60% of the cells
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.chance(0.6) { '@' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+
+    const GENERATOR: Option<fn(&mut Rng, usize) -> String> = Some(generate);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse::grid(Self::DAY, input, &['.', '@'])
+    }
+
+    fn part_one(grid: &Self::Input) -> Answer {
+        grid.positions()
+            .filter(|&pos| accessible(grid, pos))
+            .count()
+            .into()
+    }
+
+    fn part_two(grid: &Self::Input) -> Answer {
+        let mut grid = grid.clone();
+        let mut total = 0;
+
+        loop {
+            // Rolls accessible at the same time are all removed together
+            let removable: Vec<_> = grid
+                .positions()
+                .filter(|&pos| accessible(&grid, pos))
+                .collect();
+
+            if removable.is_empty() {
+                break;
+            }
+
+            for &pos in &removable {
+                grid[pos] = '.';
+            }
+            total += removable.len();
+        }
+
+        total.into()
+    }
+}
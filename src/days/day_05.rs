@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+
+use crate::{Answer, ParseError, ParseResult, Solution, parse::number, range::Range, rng::Rng};
+
+pub struct Day05;
+
+/// Fresh ingredient ID ranges followed by the available ingredient IDs
+pub struct Inventory {
+    items: Vec<u64>,
+    ranges: Vec<Range>,
+}
+
+fn remove_overlaps(ranges: Vec<Range>) -> Vec<Range> {
+    let mut fixed = vec![];
+    let mut overlapped = HashSet::new();
+
+    for (i, range) in ranges.iter().enumerate() {
+        if overlapped.contains(&i) {
+            continue;
+        }
+
+        let curr_len = fixed.len();
+
+        for (j, other) in ranges.iter().enumerate().skip(i + 1) {
+            if let Some(overlap) = range.union(other) {
+                overlapped.insert(j);
+                fixed.push(overlap);
+                break;
+            }
+        }
+
+        if curr_len == fixed.len() {
+            fixed.push(*range);
+        }
+    }
+
+    fixed
+}
+
+/// `size` overlapping fresh ranges, then `size` IDs of which about half are fresh
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let ranges: Vec<(u64, u64)> = (0..size)
+        .map(|_| {
+            let lower = rng.range(1..=1_000_000_000_000);
+            (lower, lower + rng.range(0..=10_000_000_000))
+        })
+        .collect();
+
+    let ids = (0..size).map(|_| {
+        let id = if rng.chance(0.5) {
+            let &(lower, upper) = rng.choose(&ranges);
+            rng.range(lower..=upper)
+        } else {
+            rng.range(1..=1_010_000_000_000)
+        };
+        id.to_string()
+    });
+
+    let ranges = ranges
+        .iter()
+        .map(|(lower, upper)| format!("{lower}-{upper}"));
+
+    ranges
+        .chain([String::new()])
+        .chain(ids)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Inventory;
+
+    const GENERATOR: Option<fn(&mut Rng, usize) -> String> = Some(generate);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let mut lines = input.lines();
+
+        let ranges = lines
+            .by_ref()
+            .take_while(|line| !line.is_empty())
+            .map(|line| {
+                let (left, right) = line.split_once('-').ok_or_else(|| {
+                    ParseError::at(Self::DAY, input, line, "expected a range like 3-5")
+                })?;
+
+                Ok(Range::new(
+                    number(Self::DAY, input, left)?,
+                    number(Self::DAY, input, right)?,
+                ))
+            })
+            .collect::<ParseResult<_>>()?;
+
+        let items = lines
+            .map(|line| number(Self::DAY, input, line))
+            .collect::<ParseResult<_>>()?;
+
+        Ok(Inventory { items, ranges })
+    }
+
+    fn part_one(inventory: &Self::Input) -> Answer {
+        let mut frest_ingredients: u64 = 0;
+
+        for id in &inventory.items {
+            for range in &inventory.ranges {
+                if range.contains(*id) {
+                    frest_ingredients += 1;
+                    break;
+                }
+            }
+        }
+
+        frest_ingredients.into()
+    }
+
+    fn part_two(inventory: &Self::Input) -> Answer {
+        let mut ranges = inventory.ranges.clone();
+        let mut prev_len = 0;
+
+        while prev_len != ranges.len() {
+            crate::debug!("{ranges:?}");
+            prev_len = ranges.len();
+            ranges = remove_overlaps(ranges);
+        }
+
+        ranges.iter().map(Range::len).sum::<u64>().into()
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{Answer, ParseError, ParseResult, Solution, input::Policy, parse::number, rng::Rng};
+
+pub struct Day06;
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Add,
+    Multiply,
+}
+
+impl Operation {
+    fn apply(self, nums: impl Iterator<Item = u64>) -> u64 {
+        match self {
+            Operation::Add => nums.sum(),
+            Operation::Multiply => nums.product(),
+        }
+    }
+}
+
+pub struct Worksheet {
+    /// Numbers of each row, read left to right
+    numbers: Vec<Vec<u64>>,
+    /// Numbers of each problem read column by column, top to bottom
+    columns: Vec<Vec<u64>>,
+    /// Operation of each problem
+    operations: Vec<Operation>,
+}
+
+/// Numbers written top to bottom in the columns of the block of a problem,
+/// starting at byte `start` of every row
+fn read_columns(input: &str, rows: &[&str], start: usize, width: usize) -> ParseResult<Vec<u64>> {
+    let mut numbers = Vec::new();
+
+    for idx in start..start + width {
+        let mut n: Option<u64> = None;
+        let mut gap = None;
+
+        for row in rows {
+            let cell = row.get(idx..idx + 1).unwrap_or(&row[row.len()..]);
+
+            match cell.bytes().next().filter(u8::is_ascii_digit) {
+                Some(digit) => {
+                    if let Some(gap) = gap {
+                        return Err(ParseError::at(
+                            Day06::DAY,
+                            input,
+                            gap,
+                            "gap in the middle of a column number",
+                        ));
+                    }
+                    n = Some(
+                        n.unwrap_or(0)
+                            .checked_mul(10)
+                            .and_then(|n| n.checked_add(u64::from(digit - b'0')))
+                            .ok_or_else(|| {
+                                ParseError::at(Day06::DAY, input, cell, "column number too large")
+                            })?,
+                    );
+                }
+                None if n.is_some() => gap = gap.or(Some(cell)),
+                None => {}
+            }
+        }
+
+        numbers.extend(n);
+    }
+
+    Ok(numbers)
+}
+
+/// Worksheet of `size` problems on three or four rows, with numbers of up to
+/// three digits aligned left or right in their column, so the totals of
+/// large worksheets still fit in a u64
+///
+/// Like in the puzzle input, the numbers of a problem get shorter or longer
+/// from top to bottom, so there is no gap in the middle of a column.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let rows = rng.range(3..=4) as usize;
+    let mut lines = vec![String::new(); rows + 1];
+
+    for problem in 0..size {
+        let width = rng.range(1..=3) as usize;
+        let left = rng.chance(0.5);
+
+        if problem > 0 {
+            lines.iter_mut().for_each(|line| line.push(' '));
+        }
+
+        let mut digits: Vec<u32> = (0..rows)
+            .map(|_| rng.range(1..=width as u64) as u32)
+            .collect();
+        digits.sort_unstable();
+        if rng.chance(0.5) {
+            digits.reverse();
+        }
+
+        for (line, digits) in lines[..rows].iter_mut().zip(digits) {
+            let n = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+
+            if left {
+                line.push_str(&format!("{n:<width$}"));
+            } else {
+                line.push_str(&format!("{n:>width$}"));
+            }
+        }
+
+        let op = rng.choose(&['+', '*']);
+        lines[rows].push_str(&format!("{op:<width$}"));
+    }
+
+    lines.join("\n")
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    // Part two reads the numbers column by column
+    const INPUT_POLICY: Policy = Policy::PadLines;
+
+    type Input = Worksheet;
+
+    const GENERATOR: Option<fn(&mut Rng, usize) -> String> = Some(generate);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        // Columns are read by byte, which are only chars in ASCII
+        if let Some((i, c)) = input.char_indices().find(|(_, c)| !c.is_ascii()) {
+            return Err(ParseError::at(
+                Self::DAY,
+                input,
+                &input[i..i + c.len_utf8()],
+                format!("unexpected {c:?}, only ASCII is supported"),
+            ));
+        }
+
+        let mut rows: Vec<&str> = input.lines().collect();
+        let operations = rows
+            .pop()
+            .ok_or_else(|| ParseError::at_end(Self::DAY, input, "missing operations line"))?;
+
+        let lenghts = operations
+            .split(&['*', '+'])
+            .map(|s| s.len() + 1)
+            // The first len is 0 because the first operation char is at pos 0
+            .skip(1);
+
+        let operations: Vec<_> = operations
+            .split_whitespace()
+            .zip(lenghts)
+            .map(|(op, len)| match op {
+                "+" => Ok((Operation::Add, len)),
+                "*" => Ok((Operation::Multiply, len)),
+                _ => Err(ParseError::at(
+                    Self::DAY,
+                    input,
+                    op,
+                    format!("unknown operation {op:?}, expected + or *"),
+                )),
+            })
+            .collect::<ParseResult<_>>()?;
+
+        let numbers = rows
+            .iter()
+            .map(|row| {
+                let numbers: Vec<u64> = row
+                    .split_whitespace()
+                    .map(|num| number(Self::DAY, input, num))
+                    .collect::<ParseResult<_>>()?;
+
+                if numbers.len() != operations.len() {
+                    return Err(ParseError::at(
+                        Self::DAY,
+                        input,
+                        row,
+                        format!(
+                            "expected {} numbers, one per operation, found {}",
+                            operations.len(),
+                            numbers.len()
+                        ),
+                    ));
+                }
+
+                Ok(numbers)
+            })
+            .collect::<ParseResult<_>>()?;
+
+        let mut start = 0;
+        let columns = operations
+            .iter()
+            .map(|&(_, width)| {
+                let columns = read_columns(input, &rows, start, width)?;
+                start += width;
+                Ok(columns)
+            })
+            .collect::<ParseResult<_>>()?;
+
+        Ok(Worksheet {
+            numbers,
+            columns,
+            operations: operations.into_iter().map(|(op, _)| op).collect(),
+        })
+    }
+
+    fn part_one(sheet: &Self::Input) -> Answer {
+        sheet
+            .operations
+            .iter()
+            .enumerate()
+            .map(|(i, op)| op.apply(sheet.numbers.iter().map(|row| row[i])))
+            .sum::<u64>()
+            .into()
+    }
+
+    fn part_two(sheet: &Self::Input) -> Answer {
+        sheet
+            .operations
+            .iter()
+            .zip(&sheet.columns)
+            .map(|(op, column)| op.apply(column.iter().copied()))
+            .sum::<u64>()
+            .into()
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{Answer, ParseError, ParseResult, Solution, grid::Grid, parse, rng::Rng};
+
+pub struct Day07;
+
+/// Manifold of `size` rows with the start in the middle of the first one
+///
+/// Like in the puzzle input, splitters are on every other row, never next to
+/// each other nor on the edges, where a beam would leave the manifold.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(3) | 1;
+    let start = width / 2;
+
+    (0..size.max(1))
+        .map(|row| {
+            (0..width)
+                .map(|col| match (row, col) {
+                    (0, col) if col == start => 'S',
+                    // The n-th row of splitters only has them an odd number
+                    // of columns away from the start when n is even
+                    (row, col)
+                        if row > 0
+                            && row.is_multiple_of(2)
+                            && (1..width - 1).contains(&col)
+                            && (col + start + row / 2 + 1).is_multiple_of(2)
+                            && rng.chance(0.5) =>
+                    {
+                        '^'
+                    }
+                    _ => '.',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Grid<char>;
+
+    const GENERATOR: Option<fn(&mut Rng, usize) -> String> = Some(generate);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let manifold = parse::grid(Self::DAY, input, &['.', 'S', '^'])?;
+
+        if manifold.is_empty() {
+            return Err(ParseError::at_end(Self::DAY, input, "empty manifold"));
+        }
+
+        // A beam split on an edge would leave the manifold
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            if let Some(edge) = [&line[..1], &line[line.len() - 1..]]
+                .into_iter()
+                .find(|&cell| cell == "^")
+            {
+                return Err(ParseError::at(
+                    Self::DAY,
+                    input,
+                    edge,
+                    "splitter on the edge of the manifold",
+                ));
+            }
+        }
+
+        Ok(manifold)
+    }
+
+    fn part_one(manifold: &Self::Input) -> Answer {
+        let mut prev: Vec<_> = manifold.row(0).iter().map(|&c| matches!(c, 'S')).collect();
+
+        let splits = manifold.rows().skip(1).map(|line| {
+            line.iter().enumerate().fold(0, |mut acc, (i, &c)| {
+                if prev[i] && c == '^' {
+                    prev[i] = false;
+                    prev[i - 1] = true;
+                    prev[i + 1] = true;
+                    acc += 1;
+                }
+                acc
+            })
+        });
+
+        splits.sum::<u64>().into()
+    }
+
+    fn part_two(manifold: &Self::Input) -> Answer {
+        let mut prev: Vec<u64> = manifold
+            .row(0)
+            .iter()
+            .map(|&c| if matches!(c, 'S') { 1 } else { 0 })
+            .collect();
+
+        manifold.rows().skip(1).for_each(|line| {
+            line.iter()
+                .enumerate()
+                .filter(|&(_, &c)| c == '^')
+                .for_each(|(i, _)| {
+                    prev[i - 1] += prev[i];
+                    prev[i + 1] += prev[i];
+                    prev[i] = 0;
+                });
+        });
+
+        prev.iter().sum::<u64>().into()
+    }
+}
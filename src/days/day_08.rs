@@ -0,0 +1,251 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    Alternative, Answer, ParseError, ParseResult, Part, Solution, geom::Point3, graph::UnionFind,
+    parse::number, rng::Rng,
+};
+
+pub struct Day08;
+
+/// Indices of two junction boxes and their distance
+type PointPair = (usize, usize, f32);
+
+/// Junction boxes and all their pairs sorted by distance
+pub struct Playground {
+    points: Vec<Point3>,
+    dists: Vec<PointPair>,
+    /// Number of closest pairs to connect in part one
+    n_max_pairs: usize,
+}
+
+fn parse_points(input: &str) -> ParseResult<Vec<Point3>> {
+    input
+        .lines()
+        .map(|line| {
+            let coords: Vec<i64> = line
+                .split(',')
+                .map(|coord| number(Day08::DAY, input, coord))
+                .collect::<ParseResult<_>>()?;
+
+            let [x, y, z] = coords.try_into().map_err(|coords: Vec<_>| {
+                ParseError::at(
+                    Day08::DAY,
+                    input,
+                    line,
+                    format!("expected 3 coordinates, found {}", coords.len()),
+                )
+            })?;
+
+            Ok(Point3 { x, y, z })
+        })
+        .collect()
+}
+
+fn compute_distances(points: &[Point3]) -> Vec<PointPair> {
+    let mut dists = Vec::with_capacity(points.len() - 1);
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            let dist = points[i].distance(&points[j]);
+            dists.push((i, j, dist));
+        }
+    }
+
+    // Sort by distance
+    dists.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap());
+
+    dists
+}
+
+fn merge_circuits(mut sets: Vec<BTreeSet<usize>>) -> Vec<BTreeSet<usize>> {
+    let mut skip = Vec::new();
+
+    for i in 0..sets.len() {
+        if skip.contains(&i) {
+            continue;
+        }
+
+        for j in i + 1..sets.len() {
+            if sets[i].is_disjoint(&sets[j]) {
+                continue;
+            };
+
+            let union = &sets[i] | &sets[j];
+            sets[i] = union;
+
+            skip.push(j);
+        }
+    }
+
+    sets.into_iter()
+        .enumerate()
+        .filter_map(|(i, s)| (!skip.contains(&i)).then_some(s))
+        .collect()
+}
+
+fn part_one_union_find(playground: &Playground) -> Answer {
+    let mut circuits = UnionFind::new(playground.points.len());
+
+    for &(i, j, _) in playground.dists.iter().take(playground.n_max_pairs) {
+        circuits.union(i, j);
+    }
+
+    let mut sizes = circuits.sizes();
+    sizes.sort_by_key(|&size| std::cmp::Reverse(size));
+
+    sizes.iter().take(3).product::<usize>().into()
+}
+
+fn part_two_union_find(playground: &Playground) -> Answer {
+    let Playground { points, dists, .. } = playground;
+    let mut circuits = UnionFind::new(points.len());
+
+    for &(i, j, _) in dists {
+        if circuits.union(i, j) == points.len() {
+            return (points[i].x as u64 * points[j].x as u64).into();
+        }
+    }
+
+    0u64.into()
+}
+
+/// Junction boxes anywhere in the same space as the puzzle input
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(2))
+        .map(|_| {
+            let [x, y, z] = [(); 3].map(|_| rng.range(0..=99_999));
+            format!("{x},{y},{z}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Playground;
+
+    const ALTERNATIVES: &'static [Alternative<Self::Input>] = &[
+        Alternative {
+            name: "union_find",
+            part: Part::One,
+            solve: part_one_union_find,
+        },
+        Alternative {
+            name: "union_find",
+            part: Part::Two,
+            solve: part_two_union_find,
+        },
+    ];
+
+    const GENERATOR: Option<fn(&mut Rng, usize) -> String> = Some(generate);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let points = parse_points(input)?;
+
+        if points.len() < 2 {
+            return Err(ParseError::at_end(
+                Self::DAY,
+                input,
+                "expected at least two junction boxes",
+            ));
+        }
+
+        let dists = compute_distances(&points);
+
+        // The example connects the ten closest pairs, the real input a thousand
+        let n_max_pairs = if points.len() < 1000 { 10 } else { 1000 };
+
+        Ok(Playground {
+            points,
+            dists,
+            n_max_pairs,
+        })
+    }
+
+    fn part_one(playground: &Self::Input) -> Answer {
+        let dists = &playground.dists;
+
+        // Create circuits
+        let &(i, j, _) = dists.first().unwrap();
+        let mut circuits = vec![BTreeSet::from([i, j])];
+
+        for (i, j, _) in dists.iter().skip(1).take(playground.n_max_pairs - 1) {
+            let mut inserted = false;
+            for circuit in circuits.iter_mut() {
+                if circuit.contains(i) {
+                    circuit.insert(*j);
+                    inserted = true;
+                } else if circuit.contains(j) {
+                    circuit.insert(*i);
+                    inserted = true;
+                }
+            }
+
+            if !inserted {
+                circuits.push(BTreeSet::from([*i, *j]));
+            }
+        }
+
+        // Merge the circuits
+        let mut prev_len = 0;
+        while prev_len != circuits.len() {
+            prev_len = circuits.len();
+            circuits = merge_circuits(circuits);
+        }
+
+        // Sort by set size
+        circuits.sort_by_key(|set| std::cmp::Reverse(set.len()));
+
+        circuits
+            .iter()
+            .take(3)
+            .map(|set| set.len())
+            .product::<usize>()
+            .into()
+    }
+
+    // TODO: this is extremely slow
+    // - With HashSet it takes ~13 seconds
+    // - BTreeSet makes it go down to ~6 seconds
+    // - merge_circuits is the bottleneck
+    fn part_two(playground: &Self::Input) -> Answer {
+        let Playground { points, dists, .. } = playground;
+
+        let &(i, j, _) = dists.first().unwrap();
+        let mut circuits = vec![BTreeSet::from([i, j])];
+
+        // The closest pair already connects everything when there are only two boxes
+        if points.len() == 2 {
+            return (points[i].x as u64 * points[j].x as u64).into();
+        }
+
+        for &(i, j, _) in dists.iter().skip(1) {
+            let mut inserted = false;
+
+            for circuit in circuits.iter_mut() {
+                if circuit.contains(&i) {
+                    circuit.insert(j);
+                    inserted = true;
+                } else if circuit.contains(&j) {
+                    circuit.insert(i);
+                    inserted = true;
+                }
+            }
+
+            if !inserted {
+                circuits.push(BTreeSet::from([i, j]));
+            } else {
+                circuits = merge_circuits(circuits);
+            }
+
+            // Check if all boxes are connected
+            if circuits.first().unwrap().len() == points.len() {
+                let xi = points[i].x as u64;
+                let xj = points[j].x as u64;
+                return (xi * xj).into();
+            }
+        }
+
+        0u64.into()
+    }
+}
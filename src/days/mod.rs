@@ -0,0 +1,28 @@
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+
+use crate::registry::Day;
+
+/// Every registered day, in calendar order
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<day_01::Day01>(),
+        Day::new::<day_02::Day02>(),
+        Day::new::<day_03::Day03>(),
+        Day::new::<day_04::Day04>(),
+        Day::new::<day_05::Day05>(),
+        Day::new::<day_06::Day06>(),
+        Day::new::<day_07::Day07>(),
+        Day::new::<day_08::Day08>(),
+    ]
+}
+
+pub fn get(number: u8) -> Option<Day> {
+    all().into_iter().find(|day| day.number == number)
+}
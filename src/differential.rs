@@ -0,0 +1,215 @@
+//! Differential testing of the alternative implementations of a day
+//!
+//! Every implementation of a part is run on random inputs from the day's
+//! generator, and the first disagreement is shrunk to a minimal
+//! counterexample by removing lines. The seed can be set through `AOC_SEED`
+//! to reproduce a failure.
+
+use std::{
+    env,
+    fmt::{self, Display},
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::{ParseError, Part, registry::Day, rng::Rng, runner};
+
+/// Environment variable overriding the seed of the generated inputs
+pub const SEED_VAR: &str = "AOC_SEED";
+
+pub const DEFAULT_SEED: u64 = 2025;
+
+/// Seed from `AOC_SEED`, or the default one
+pub fn seed() -> u64 {
+    env::var(SEED_VAR)
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(DEFAULT_SEED)
+}
+
+/// Input on which the implementations of a day disagree or panic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+    pub day: u8,
+    pub seed: u64,
+    pub input: String,
+    pub failures: Vec<String>,
+}
+
+impl Display for Counterexample {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "day {:02} failed with seed {}", self.day, self.seed)?;
+        for failure in &self.failures {
+            writeln!(f, "  {failure}")?;
+        }
+        write!(f, "input:\n{}", self.input)
+    }
+}
+
+/// How the implementations of a day fail on `input`, empty if they all agree
+fn failures(day: &Day, input: &str) -> Result<Vec<String>, ParseError> {
+    let parsed = day.parse(input)?;
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let (one, two) = (parsed.solve(Part::One), parsed.solve(Part::Two));
+
+        parsed
+            .alternatives()
+            .into_iter()
+            .filter_map(|(name, part, answer)| {
+                let expected = match part {
+                    Part::One => &one,
+                    Part::Two => &two,
+                };
+                (answer != *expected)
+                    .then(|| format!("part {part}: {name} gives {answer}, expected {expected}"))
+            })
+            .collect()
+    }));
+
+    Ok(result
+        .unwrap_or_else(|payload| vec![format!("panicked: {}", runner::panic_message(payload))]))
+}
+
+/// Remove lines from a failing input for as long as it keeps failing
+fn shrink(day: &Day, input: &str, mut failures: Vec<String>) -> (String, Vec<String>) {
+    let mut lines: Vec<&str> = input.lines().collect();
+    let mut chunk = lines.len() / 2;
+
+    while chunk > 0 {
+        let mut removed = false;
+        let mut start = 0;
+
+        while start < lines.len() {
+            let end = (start + chunk).min(lines.len());
+            let candidate = [&lines[..start], &lines[end..]].concat();
+
+            match self::failures(day, &candidate.join("\n")) {
+                Ok(found) if !found.is_empty() => {
+                    lines = candidate;
+                    failures = found;
+                    removed = true;
+                }
+                _ => start += chunk,
+            }
+        }
+
+        // Try chunks of the same size again while they can be removed
+        if !removed {
+            chunk /= 2;
+        }
+    }
+
+    (lines.join("\n"), failures)
+}
+
+/// Check that the implementations of a day agree on `cases` generated inputs,
+/// growing from 1 to `max_size` lines
+///
+/// Days without a generator trivially pass.
+pub fn check(day: &Day, seed: u64, cases: usize, max_size: usize) -> Result<(), Counterexample> {
+    let mut rng = Rng::new(seed);
+
+    for case in 0..cases {
+        let size = 1 + case * max_size.saturating_sub(1) / cases.max(2).saturating_sub(1);
+        let Some(input) = day.generate(&mut rng, size) else {
+            return Ok(());
+        };
+
+        let (input, failures) = match failures(day, &input) {
+            Ok(failures) if failures.is_empty() => continue,
+            Ok(failures) => shrink(day, &input, failures),
+            Err(err) => (
+                input,
+                vec![format!("generated input does not parse: {err}")],
+            ),
+        };
+
+        return Err(Counterexample {
+            day: day.number,
+            seed,
+            input,
+            failures,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alternative, Answer, ParseResult, Solution, days, parse::number};
+
+    /// Sums lines of numbers, with an alternative forgetting the ones ending in 7
+    struct Sevens;
+
+    impl Solution for Sevens {
+        const DAY: u8 = 25;
+
+        type Input = Vec<u64>;
+
+        const ALTERNATIVES: &'static [Alternative<Self::Input>] = &[Alternative {
+            name: "skip_sevens",
+            part: Part::Two,
+            solve: |numbers| numbers.iter().filter(|&n| n % 10 != 7).sum::<u64>().into(),
+        }];
+
+        const GENERATOR: Option<fn(&mut Rng, usize) -> String> = Some(|rng, size| {
+            (0..size)
+                .map(|_| rng.below(100).to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        });
+
+        fn parse(input: &str) -> ParseResult<Self::Input> {
+            input
+                .lines()
+                .map(|line| number(Self::DAY, input, line))
+                .collect()
+        }
+
+        fn part_one(numbers: &Self::Input) -> Answer {
+            numbers.len().into()
+        }
+
+        fn part_two(numbers: &Self::Input) -> Answer {
+            numbers.iter().sum::<u64>().into()
+        }
+    }
+
+    #[test]
+    fn test_check_shrinks_disagreement() {
+        let result = check(&Day::new::<Sevens>(), 1, 100, 20).unwrap_err();
+        let n: u64 = result.input.parse().unwrap();
+
+        assert_eq!(n % 10, 7);
+        assert_eq!(
+            result.failures,
+            vec![format!("part two: skip_sevens gives 0, expected {n}")]
+        );
+    }
+
+    #[test]
+    fn test_generators_follow_the_seed() {
+        for day in days::all() {
+            let generate = |seed| day.generate(&mut Rng::new(seed), 10);
+            // Days scaffolded from the template have no generator until one
+            // is written
+            if generate(1).is_none() {
+                continue;
+            }
+
+            assert_eq!(generate(1), generate(1));
+            assert_ne!(generate(1), generate(2));
+        }
+    }
+
+    #[test]
+    fn test_days_agree() {
+        for day in days::all() {
+            if let Err(counterexample) = check(&day, seed(), 100, 20) {
+                panic!("{counterexample}");
+            }
+        }
+    }
+}
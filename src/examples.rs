@@ -0,0 +1,147 @@
+//! Example inputs from the puzzle descriptions and their expected answers
+//!
+//! Examples live in `examples/day_XX[_name].txt`, and their expected answers
+//! in `examples/expected.txt`, one `<example> <part> <answer>` per line.
+//! A test is generated for every line of the manifest.
+
+use std::{fs, path::Path};
+
+use crate::{
+    Answer, Part,
+    answers::{Answers, Entry, Verdict},
+};
+
+pub const MANIFEST: &str = "expected.txt";
+
+/// Expected answer of one part of an example
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    /// File stem of the example, e.g. `day_06` or `day_06_padding`
+    pub example: String,
+    pub part: Part,
+    pub answer: Answer,
+}
+
+/// File stem of an example, as used in the manifest
+pub fn name(day: u8, name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("day_{day:02}_{name}"),
+        None => format!("day_{day:02}"),
+    }
+}
+
+/// Day number of an example file stem
+pub fn day(example: &str) -> Option<u8> {
+    example.strip_prefix("day_")?.get(..2)?.parse().ok()
+}
+
+pub fn parse_manifest(s: &str) -> Result<Vec<Expected>, String> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            let mut fields = line.splitn(3, ' ');
+            let example = fields.next().filter(|example| day(example).is_some());
+            let part = match fields.next() {
+                Some("one") => Some(Part::One),
+                Some("two") => Some(Part::Two),
+                _ => None,
+            };
+            let answer = fields.next().map(str::trim);
+
+            match (example, part, answer) {
+                (Some(example), Some(part), Some(answer)) if !answer.is_empty() => Ok(Expected {
+                    example: example.to_string(),
+                    part,
+                    answer: answer.parse().unwrap(),
+                }),
+                _ => Err(format!(
+                    "{MANIFEST} line {}: expected `<day_XX[_name]> <one|two> <answer>`",
+                    i + 1
+                )),
+            }
+        })
+        .collect()
+}
+
+pub fn load_manifest(dir: &Path) -> Result<Vec<Expected>, String> {
+    let path = dir.join(MANIFEST);
+    let content = fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+
+    parse_manifest(&content)
+}
+
+/// Expected answers of an example, as confirmed answers to check solutions against
+pub fn answers(dir: &Path, example: &str) -> Result<Answers, String> {
+    let entries = load_manifest(dir)?
+        .into_iter()
+        .filter(|expected| expected.example == example)
+        .map(|expected| Entry {
+            part: expected.part,
+            verdict: Verdict::Confirmed,
+            answer: expected.answer,
+        })
+        .collect();
+
+    Ok(Answers::new(entries))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::days;
+
+    fn examples_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("examples")
+    }
+
+    fn check_example(example: &str, part: Part, expected: &str) {
+        let number = day(example).unwrap();
+        let day = days::get(number).unwrap_or_else(|| panic!("day {number} is not registered"));
+
+        let path = examples_dir().join(format!("{example}.txt"));
+        let input = fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+
+        let result = day.parse(&input).unwrap().solve(part);
+
+        assert_eq!(result, expected.parse().unwrap())
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+
+    #[test]
+    fn test_every_day_has_examples() {
+        let manifest = load_manifest(&examples_dir()).unwrap();
+
+        for day in days::all() {
+            for part in Part::BOTH {
+                assert!(
+                    manifest
+                        .iter()
+                        .any(|expected| super::day(&expected.example) == Some(day.number)
+                            && expected.part == part),
+                    "day {} has no example for part {part}",
+                    day.number
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_manifest() {
+        let expected = vec![Expected {
+            example: "day_06_padding".to_string(),
+            part: Part::Two,
+            answer: Answer::from(42),
+        }];
+        let result = parse_manifest("# comment\n\nday_06_padding two 42\n");
+
+        assert_eq!(result, Ok(expected));
+        assert!(parse_manifest("input two 42").is_err());
+        assert!(parse_manifest("day_06 three 42").is_err());
+    }
+}
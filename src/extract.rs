@@ -0,0 +1,139 @@
+//! Extract the example inputs of a puzzle from its saved HTML page
+//!
+//! Puzzle descriptions show their examples in `<pre><code>` blocks, with
+//! the interesting parts wrapped in `<em>` tags. Copying them from the page
+//! keeps the whitespace that pasting from a browser tends to lose.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::examples;
+
+/// Text of an HTML fragment: tags dropped and entities decoded
+fn text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(i) = rest.find(['<', '&']) {
+        text += &rest[..i];
+        rest = &rest[i..];
+
+        if rest.starts_with('<') {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+
+        match rest
+            .find(';')
+            .and_then(|end| Some((entity(&rest[1..end])?, end)))
+        {
+            Some((c, end)) => {
+                text.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                text.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    text + rest
+}
+
+/// Character of an entity, without its `&` and `;`
+fn entity(name: &str) -> Option<char> {
+    let code = match name {
+        "lt" => '<' as u32,
+        "gt" => '>' as u32,
+        "amp" => '&' as u32,
+        "quot" => '"' as u32,
+        "apos" => '\'' as u32,
+        "nbsp" => 0xa0,
+        _ => match name.strip_prefix('#')? {
+            hex if hex.starts_with(['x', 'X']) => u32::from_str_radix(&hex[1..], 16).ok()?,
+            decimal => decimal.parse().ok()?,
+        },
+    };
+
+    char::from_u32(code)
+}
+
+/// Text of every `<pre><code>` block of a page, in order
+pub fn code_blocks(html: &str) -> Vec<String> {
+    html.split("<pre><code>")
+        .skip(1)
+        .filter_map(|block| block.split_once("</code></pre>"))
+        .map(|(block, _)| text(block))
+        .collect()
+}
+
+/// First lines of a block, to recognise it among the others
+pub fn preview(block: &str, lines: usize) -> String {
+    let mut preview: Vec<&str> = block.lines().take(lines).collect();
+    if block.lines().count() > lines {
+        preview.push("...");
+    }
+    preview.join("\n")
+}
+
+/// Write a block as an example of a day, refusing to overwrite an existing one
+pub fn write_example(
+    dir: &Path,
+    day: u8,
+    name: Option<&str>,
+    block: &str,
+) -> Result<PathBuf, String> {
+    let path = dir.join(format!("{}.txt", examples::name(day, name)));
+    if path.exists() {
+        return Err(format!("{} already exists", path.display()));
+    }
+
+    fs::write(&path, block).map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::scratch_path;
+
+    const PAGE: &str = "<article><p>For example:</p>\n\
+        <pre><code>123 328  51 64 \n 45 64  387 23 \n*   +   *   +  \n</code></pre>\n\
+        <p>Here, <code>x</code> is inline.</p>\n\
+        <pre><code>a &lt;<em>b</em>&gt; &amp;&#x41;&#66; &unknown; & c\n</code></pre></article>";
+
+    #[test]
+    fn test_code_blocks() {
+        let expected = vec![
+            "123 328  51 64 \n 45 64  387 23 \n*   +   *   +  \n".to_string(),
+            "a <b> &AB &unknown; & c\n".to_string(),
+        ];
+        let result = code_blocks(PAGE);
+
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_preview() {
+        assert_eq!(preview("1\n2\n3\n", 2), "1\n2\n...");
+        assert_eq!(preview("1\n2\n", 2), "1\n2");
+    }
+
+    #[test]
+    fn test_write_example() {
+        let dir = scratch_path("extract");
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = write_example(&dir, 6, Some("page"), "1 \n2\n");
+        let again = write_example(&dir, 6, Some("page"), "3\n");
+        let content = fs::read_to_string(dir.join("day_06_page.txt"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(path, Ok(dir.join("day_06_page.txt")));
+        assert!(again.is_err());
+        assert_eq!(content.unwrap(), "1 \n2\n");
+    }
+}
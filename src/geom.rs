@@ -0,0 +1,38 @@
+//! Points in space
+
+/// Point on an integer grid in three dimensions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    /// Euclidean distance, in single precision
+    pub fn distance(&self, other: &Self) -> f32 {
+        [(self.x, other.x), (self.y, other.y), (self.z, other.z)]
+            .iter()
+            .map(|&(s, o)| (s as f32 - o as f32).powi(2))
+            .sum::<f32>()
+            .sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        let a = Point3::new(1, 2, 3);
+        let b = Point3::new(3, 5, 9);
+
+        assert_eq!(a.distance(&b), 7.0);
+        assert_eq!(b.distance(&a), a.distance(&b));
+    }
+}
@@ -0,0 +1,128 @@
+//! Dense two-dimensional grids, the most common shape of puzzle input
+
+use std::ops::{Index, IndexMut};
+
+/// Rectangular grid, stored row by row and indexed by `(row, col)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid from rows, which must all have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "rows of different lengths"
+        );
+
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        (row < self.height && col < self.width).then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Positions of every cell, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Positions of the up to 8 cells around a cell, diagonals included
+    pub fn neighbours(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (height, width) = (self.height, self.width);
+
+        (-1..=1)
+            .flat_map(|dr| (-1..=1).map(move |dc| (dr, dc)))
+            .filter(|&delta| delta != (0, 0))
+            .filter_map(move |(dr, dc)| {
+                let row = row.checked_add_signed(dr)?;
+                let col = col.checked_add_signed(dc)?;
+                (row < height && col < width).then_some((row, col))
+            })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("({row}, {col}) is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            row < self.height && col < self.width,
+            "({row}, {col}) is outside the grid"
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])
+    }
+
+    #[test]
+    fn test_index() {
+        let mut grid = grid();
+        grid[(1, 2)] = 9;
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(0, 1)], 2);
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.row(1), &[4, 5, 9]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.positions().last(), Some((1, 2)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        let corner: Vec<_> = grid.neighbours(0, 0).collect();
+        let middle: Vec<_> = grid.neighbours(1, 1).collect();
+
+        assert_eq!(corner, vec![(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(middle, vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)]);
+    }
+}
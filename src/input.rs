@@ -0,0 +1,221 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io::{self, ErrorKind, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable overriding the directory of the puzzle inputs
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// How a day wants its input prepared before parsing
+///
+/// Whatever the policy, a leading byte order mark is stripped and CRLF line
+/// endings are turned into LF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Policy {
+    /// Drop the newlines at the end of the input
+    #[default]
+    TrimNewline,
+    /// Drop the newlines at the end of the input and pad every line with
+    /// spaces to the width of the longest one, for column based inputs
+    PadLines,
+    /// Keep the input as is
+    Preserve,
+}
+
+/// Prepare an input for parsing according to `policy`
+pub fn normalize(input: &str, policy: Policy) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let input = input.replace("\r\n", "\n");
+
+    match policy {
+        Policy::TrimNewline => input.trim_end_matches('\n').to_string(),
+        Policy::PadLines => {
+            let lines: Vec<&str> = input.trim_end_matches('\n').lines().collect();
+            let width = lines
+                .iter()
+                .map(|line| line.chars().count())
+                .max()
+                .unwrap_or(0);
+
+            lines
+                .iter()
+                .map(|line| format!("{line:<width$}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        Policy::Preserve => input,
+    }
+}
+
+/// Where to read the input of a day from
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Source {
+    /// `day_XX.txt` in the inputs directory
+    #[default]
+    Puzzle,
+    /// An explicit file path
+    File(PathBuf),
+    Stdin,
+    /// `day_XX.txt`, or `day_XX_<name>.txt` if named, in the examples directory
+    Example(Option<String>),
+}
+
+impl Source {
+    /// Parse the value of `--input`, where `-` stands for stdin
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Path of the input file of `day`, `None` when reading from stdin
+    pub fn path(&self, day: u8, dirs: &Dirs) -> Option<PathBuf> {
+        match self {
+            Source::Puzzle => Some(dirs.inputs.join(format!("day_{day:02}.txt"))),
+            Source::File(path) => Some(path.clone()),
+            Source::Stdin => None,
+            Source::Example(None) => Some(dirs.examples.join(format!("day_{day:02}.txt"))),
+            Source::Example(Some(name)) => {
+                Some(dirs.examples.join(format!("day_{day:02}_{name}.txt")))
+            }
+        }
+    }
+
+    pub fn read(&self, day: u8, dirs: &Dirs) -> Result<String, InputError> {
+        let Some(path) = self.path(day, dirs) else {
+            let mut input = String::new();
+            return match io::stdin().read_to_string(&mut input) {
+                Ok(_) => Ok(input),
+                Err(err) => Err(InputError { path: None, err }),
+            };
+        };
+
+        fs::read_to_string(&path).map_err(|err| InputError {
+            path: Some(path),
+            err,
+        })
+    }
+}
+
+/// Directories the puzzle inputs, examples and known answers are looked up in
+#[derive(Debug, Clone)]
+pub struct Dirs {
+    pub inputs: PathBuf,
+    pub examples: PathBuf,
+    pub answers: PathBuf,
+    /// Machine specific benchmark baselines
+    pub bench: PathBuf,
+}
+
+impl Dirs {
+    /// Directories relative to the crate root, so the runner works from any
+    /// working directory, with the inputs overridable through `AOC_INPUT_DIR`
+    pub fn from_env() -> Self {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        Dirs {
+            inputs: env::var_os(INPUT_DIR_VAR).map_or_else(|| root.join("inputs"), PathBuf::from),
+            examples: root.join("examples"),
+            answers: root.join("answers"),
+            bench: root.join("target").join("bench"),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Dirs {
+    /// Directories laid out under `root` like under the crate root
+    pub(crate) fn under(root: &Path) -> Self {
+        Dirs {
+            inputs: root.join("inputs"),
+            examples: root.join("examples"),
+            answers: root.join("answers"),
+            bench: root.join("bench"),
+        }
+    }
+}
+
+/// Path in the temp directory, named after a test and unique to the process
+#[cfg(test)]
+pub(crate) fn scratch_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("aoc2025-{name}-{}", std::process::id()))
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    path: Option<PathBuf>,
+    err: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.path {
+            Some(path) if self.err.kind() == ErrorKind::NotFound => write!(
+                f,
+                "input file {} not found (set {INPUT_DIR_VAR} or pass --input)",
+                path.display()
+            ),
+            Some(path) => write!(f, "failed to read {}: {}", path.display(), self.err),
+            None => write!(f, "failed to read stdin: {}", self.err),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        let input = "\u{feff}12 3\r\n 4\r\n\r\n";
+
+        assert_eq!(normalize(input, Policy::TrimNewline), "12 3\n 4");
+        assert_eq!(normalize(input, Policy::PadLines), "12 3\n 4  ");
+        assert_eq!(normalize(input, Policy::Preserve), "12 3\n 4\n\n");
+        assert_eq!(normalize("", Policy::PadLines), "");
+    }
+
+    fn dirs() -> Dirs {
+        Dirs {
+            inputs: PathBuf::from("/mnt/inputs"),
+            examples: PathBuf::from("examples"),
+            answers: PathBuf::from("answers"),
+            bench: PathBuf::from("bench"),
+        }
+    }
+
+    #[test]
+    fn test_path() {
+        let dirs = dirs();
+
+        assert_eq!(
+            Source::Puzzle.path(5, &dirs),
+            Some(PathBuf::from("/mnt/inputs/day_05.txt"))
+        );
+        assert_eq!(
+            Source::Example(Some("edge".to_string())).path(6, &dirs),
+            Some(PathBuf::from("examples/day_06_edge.txt"))
+        );
+        assert_eq!(Source::from_arg("-").path(1, &dirs), None);
+        assert_eq!(
+            Source::from_arg("my.txt").path(1, &dirs),
+            Some(PathBuf::from("my.txt"))
+        );
+    }
+
+    #[test]
+    fn test_missing_file_error() {
+        let expected = "input file /mnt/inputs/day_07.txt not found \
+                        (set AOC_INPUT_DIR or pass --input)";
+        let result = Source::Puzzle.read(7, &dirs()).unwrap_err();
+
+        assert_eq!(result.to_string(), expected)
+    }
+}
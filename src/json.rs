@@ -0,0 +1,203 @@
+//! Minimal JSON for the machine-readable runner output, written by `run` and read back by `watch`
+
+use std::{fmt::Write, iter::Peekable, str::Chars};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Null,
+    Number(String),
+    String(String),
+}
+
+impl From<Option<Value>> for Value {
+    fn from(value: Option<Value>) -> Self {
+        value.unwrap_or(Value::Null)
+    }
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Value {
+                fn from(n: $t) -> Self {
+                    Value::Number(n.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(u8, u64, u128, usize);
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::String(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::String(s)
+    }
+}
+
+fn escape(s: &str, out: &mut String) {
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+}
+
+/// Serialise the fields of a flat object on a single line
+pub fn object(fields: Vec<(&str, Value)>) -> String {
+    let mut out = String::from("{");
+
+    for (i, (key, value)) in fields.into_iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+
+        escape(key, &mut out);
+        out.push(':');
+
+        match value {
+            Value::Null => out.push_str("null"),
+            Value::Number(n) => out.push_str(&n),
+            Value::String(s) => escape(&s, &mut out),
+        }
+    }
+
+    out.push('}');
+    out
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+fn expect(chars: &mut Peekable<Chars>, c: char) -> Option<()> {
+    skip_whitespace(chars);
+    chars.next_if_eq(&c).map(|_| ())
+}
+
+fn unescape(chars: &mut Peekable<Chars>) -> Option<String> {
+    expect(chars, '"')?;
+    let mut out = String::new();
+
+    loop {
+        let c = match chars.next()? {
+            '"' => return Some(out),
+            '\\' => match chars.next()? {
+                '"' => '"',
+                '\\' => '\\',
+                '/' => '/',
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'b' => '\u{8}',
+                'f' => '\u{c}',
+                'u' => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+                }
+                _ => return None,
+            },
+            c => c,
+        };
+        out.push(c);
+    }
+}
+
+fn value(chars: &mut Peekable<Chars>) -> Option<Value> {
+    skip_whitespace(chars);
+
+    match chars.peek()? {
+        '"' => unescape(chars).map(Value::String),
+        'n' => {
+            let word: String = chars.by_ref().take(4).collect();
+            (word == "null").then_some(Value::Null)
+        }
+        '-' | '0'..='9' => {
+            let mut n = String::new();
+            while let Some(c) =
+                chars.next_if(|c| matches!(c, '-' | '+' | '.' | 'e' | 'E' | '0'..='9'))
+            {
+                n.push(c);
+            }
+            Some(Value::Number(n))
+        }
+        _ => None,
+    }
+}
+
+/// Parse a flat object as written by [`object`], `None` if it is not one
+pub fn parse_object(s: &str) -> Option<Vec<(String, Value)>> {
+    let mut chars = s.chars().peekable();
+    let mut fields = Vec::new();
+
+    expect(&mut chars, '{')?;
+
+    if expect(&mut chars, '}').is_none() {
+        loop {
+            let key = unescape(&mut chars)?;
+            expect(&mut chars, ':')?;
+            fields.push((key, value(&mut chars)?));
+
+            skip_whitespace(&mut chars);
+            match chars.next()? {
+                ',' => continue,
+                '}' => break,
+                _ => return None,
+            }
+        }
+    }
+
+    skip_whitespace(&mut chars);
+    chars.next().is_none().then_some(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object() {
+        let expected = r#"{"day":6,"answer":"a \"b\"\n\u0001","error":null}"#;
+        let result = object(vec![
+            ("day", 6u8.into()),
+            ("answer", "a \"b\"\n\u{1}".into()),
+            ("error", Value::Null),
+        ]);
+
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_parse_object() {
+        let expected = vec![
+            ("day".to_string(), Value::Number("6".to_string())),
+            (
+                "answer".to_string(),
+                Value::String("a \"b\"\n\u{1}".to_string()),
+            ),
+            ("error".to_string(), Value::Null),
+        ];
+        let result = parse_object(r#"{"day":6,"answer":"a \"b\"\n\u0001", "error": null}"#);
+
+        assert_eq!(result, Some(expected));
+        assert_eq!(parse_object("{}"), Some(vec![]));
+        assert_eq!(parse_object(r#"{"day":6"#), None);
+        assert_eq!(parse_object(r#"{"day":6} trailing"#), None);
+    }
+}
@@ -0,0 +1,34 @@
+#[cfg(feature = "count-alloc")]
+pub mod alloc;
+pub mod answer;
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod cli;
+pub mod client;
+pub mod days;
+pub mod differential;
+pub mod examples;
+pub mod extract;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod num;
+pub mod parse;
+pub mod pool;
+pub mod range;
+pub mod registry;
+pub mod rng;
+pub mod runner;
+pub mod scaffold;
+pub mod scale;
+mod solution;
+pub mod submit;
+pub mod trace;
+pub mod watch;
+
+pub use answer::Answer;
+pub use parse::{ParseError, ParseResult};
+pub use solution::{Alternative, Part, Solution};
@@ -1,3 +1,370 @@
-fn main() {
-    println!("Hello, world!");
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Instant,
+};
+
+use aoc2025::{
+    Part,
+    baseline::{self, Baseline, Change},
+    bench,
+    cli::{self, Command, RunArgs, Selection},
+    client::{self, Fetched},
+    days, examples, extract,
+    input::Dirs,
+    registry::Day,
+    rng::Rng,
+    runner::{self, Format},
+    scaffold, scale,
+    submit::{self, Outcome},
+    trace::{self, Filter},
+    watch,
+};
+
+fn select(selection: &Selection) -> Result<Vec<Day>, String> {
+    match selection {
+        Selection::All => Ok(days::all()),
+        Selection::Day(number) => match days::get(*number) {
+            Some(day) => Ok(vec![day]),
+            None => Err(format!("day {number} is not solved yet")),
+        },
+    }
+}
+
+fn run(args: RunArgs) -> Result<ExitCode, String> {
+    let dirs = Dirs::from_env();
+    let days = select(&args.selection)?;
+
+    let start = Instant::now();
+    let results = runner::run_all(
+        &days,
+        &args.source,
+        &dirs,
+        &args.parts,
+        args.jobs,
+        args.timeout,
+    );
+    let wall_time = start.elapsed();
+
+    let mut status = ExitCode::SUCCESS;
+
+    for (day, result) in days.iter().zip(&results) {
+        match result {
+            Ok(report) => {
+                let regressions = report.regressions();
+                let failures = report.failures();
+
+                if args.format == Format::Text && regressions > 0 {
+                    eprintln!(
+                        "error: day {:02}: {regressions} solution(s) contradict the known answers",
+                        day.number
+                    );
+                }
+                if args.format == Format::Text && failures > 0 {
+                    eprintln!(
+                        "error: day {:02}: {failures} part(s) panicked or timed out",
+                        day.number
+                    );
+                }
+                if regressions + failures > 0 {
+                    status = ExitCode::FAILURE;
+                }
+            }
+            Err(err) => {
+                if args.format == Format::Text {
+                    eprintln!("error: day {:02}: {err}", day.number);
+                }
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    match (args.format, &args.selection) {
+        (Format::Json, _) => {
+            for (day, result) in days.iter().zip(&results) {
+                match result {
+                    Ok(report) => {
+                        for part in &report.parts {
+                            println!("{}", runner::part_json(report, part));
+                        }
+                    }
+                    Err(err) => {
+                        for &part in &args.parts {
+                            println!("{}", runner::error_json(day.number, part, &err.to_string()));
+                        }
+                    }
+                }
+            }
+        }
+        (Format::Text, Selection::Day(_)) => {
+            let reports: Vec<_> = results.into_iter().filter_map(Result::ok).collect();
+
+            for report in &reports {
+                println!("Day {:02}", report.day);
+                runner::print_answers(report);
+            }
+
+            if !reports.is_empty() {
+                println!();
+                runner::print_timings(&reports);
+            }
+        }
+        (Format::Text, Selection::All) => runner::print_summary(&days, &results, wall_time),
+    }
+
+    Ok(status)
+}
+
+fn bench(args: RunArgs, config: bench::Config, save_baseline: bool) -> Result<ExitCode, String> {
+    let dirs = Dirs::from_env();
+    let path = dirs.bench.join("baseline.txt");
+    let mut baseline = Baseline::load(&path)?;
+    let mut status = ExitCode::SUCCESS;
+    let mut regressions = 0;
+
+    // Timings of another input say nothing about this one
+    let source = baseline::source_key(&args.source);
+    if save_baseline && source.is_none() {
+        return Err("cannot save a baseline of an input read from stdin".to_string());
+    }
+
+    bench::print_header();
+
+    for day in select(&args.selection)? {
+        match bench::bench(&day, &args.source, &dirs, &args.parts, &config) {
+            Ok(results) => {
+                for (phase, stats) in results {
+                    let change = source
+                        .as_deref()
+                        .and_then(|source| baseline.get(day.number, source, phase))
+                        .map(|saved| Change::between(saved, &stats));
+                    bench::print_stats(day.number, phase, &stats, change);
+
+                    if change.is_some_and(|change| change.is_regression()) {
+                        regressions += 1;
+                    }
+                    if let (true, Some(source)) = (save_baseline, &source) {
+                        baseline.insert(day.number, source, phase, stats);
+                    }
+                }
+            }
+            Err(err) => {
+                eprintln!("error: day {:02}: {err}", day.number);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if regressions > 0 {
+        eprintln!("error: {regressions} phase(s) significantly slower than the baseline");
+        status = ExitCode::FAILURE;
+    }
+
+    if save_baseline {
+        baseline
+            .save(&path)
+            .map_err(|err| format!("failed to save {}: {err}", path.display()))?;
+        println!("\nSaved baseline to {}", path.display());
+    }
+
+    Ok(status)
+}
+
+#[cfg(feature = "count-alloc")]
+fn alloc(args: RunArgs) -> Result<ExitCode, String> {
+    use aoc2025::alloc;
+
+    let dirs = Dirs::from_env();
+    let mut status = ExitCode::SUCCESS;
+
+    alloc::print_header();
+
+    for day in select(&args.selection)? {
+        match alloc::profile(&day, &args.source, &dirs, &args.parts) {
+            Ok(results) => {
+                for (phase, usage) in results {
+                    alloc::print_usage(day.number, phase, &usage);
+                }
+            }
+            Err(err) => {
+                eprintln!("error: day {:02}: {err}", day.number);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    Ok(status)
+}
+
+#[cfg(not(feature = "count-alloc"))]
+fn alloc(_: RunArgs) -> Result<ExitCode, String> {
+    Err("allocations are only counted when built with `--features count-alloc`".to_string())
+}
+
+fn new(day: u8) -> Result<ExitCode, String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let path = scaffold::scaffold(root, day).map_err(|err| err.to_string())?;
+    println!("Created {}", path.display());
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn watch(day: u8) -> Result<ExitCode, String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    watch::watch(root, day, &Dirs::from_env())?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn fetch(day: u8) -> Result<ExitCode, String> {
+    match client::fetch(&client::Config::from_env(), day, &Dirs::from_env())? {
+        Fetched::Cached(path) => println!("Already fetched to {}", path.display()),
+        Fetched::Downloaded(path) => println!("Fetched to {}", path.display()),
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn submit(day: u8, part: Part) -> Result<ExitCode, String> {
+    let day = select(&Selection::Day(day))?[0];
+    let (answer, outcome) = submit::submit(
+        &client::Config::from_env(),
+        &day,
+        part,
+        &Dirs::from_env(),
+        runner::DEFAULT_TIMEOUT,
+    )?;
+    println!("Part {part}: {answer} is {outcome}");
+
+    match outcome {
+        Outcome::Correct | Outcome::AlreadySolved => Ok(ExitCode::SUCCESS),
+        _ => Ok(ExitCode::FAILURE),
+    }
+}
+
+/// Ask which block to save, showing the start of each
+fn choose(blocks: &[String]) -> Result<usize, String> {
+    for (i, block) in blocks.iter().enumerate() {
+        println!("[{}]\n{}\n", i + 1, extract::preview(block, 5));
+    }
+    print!("Block to save [1-{}]: ", blocks.len());
+    io::stdout().flush().map_err(|err| err.to_string())?;
+
+    let mut line = String::new();
+    io::stdin()
+        .read_line(&mut line)
+        .map_err(|err| format!("failed to read the choice: {err}"))?;
+
+    match line.trim().parse() {
+        Ok(n @ 1..) if n <= blocks.len() => Ok(n - 1),
+        _ => Err(format!("invalid choice {:?}", line.trim())),
+    }
+}
+
+fn extract(
+    day: u8,
+    from: Option<PathBuf>,
+    block: Option<usize>,
+    name: Option<String>,
+) -> Result<ExitCode, String> {
+    let dirs = Dirs::from_env();
+    let page = match from {
+        Some(path) => path,
+        None => match client::fetch_page(&client::Config::from_env(), day, &dirs)? {
+            Fetched::Cached(path) | Fetched::Downloaded(path) => path,
+        },
+    };
+
+    let html = fs::read_to_string(&page)
+        .map_err(|err| format!("failed to read {}: {err}", page.display()))?;
+    let blocks = extract::code_blocks(&html);
+
+    let index = match (block, blocks.len()) {
+        (_, 0) => return Err(format!("no code blocks in {}", page.display())),
+        (Some(n), len) if n > len => {
+            return Err(format!("block {n} requested, {} has {len}", page.display()));
+        }
+        (Some(n), _) => n - 1,
+        (None, 1) => 0,
+        (None, _) => choose(&blocks)?,
+    };
+
+    let path = extract::write_example(&dirs.examples, day, name.as_deref(), &blocks[index])?;
+    println!(
+        "Wrote {}, add its expected answers to {}",
+        path.display(),
+        examples::MANIFEST
+    );
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn generate(day: u8, size: usize, seed: u64) -> Result<ExitCode, String> {
+    let input = select(&Selection::Day(day))?[0]
+        .generate(&mut Rng::new(seed), size)
+        .ok_or_else(|| format!("day {day} has no input generator"))?;
+    println!("{input}");
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn scale(day: u8, parts: &[Part], config: &scale::Config) -> Result<ExitCode, String> {
+    let day = select(&Selection::Day(day))?[0];
+    println!(
+        "Day {:02}, n is the size of the input in bytes\n",
+        day.number
+    );
+    scale::scale(&day, parts, config)?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let verbosity = cli::take_verbosity(&mut args);
+
+    match Filter::from_env(verbosity) {
+        Ok(filter) => trace::init(filter),
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let command = match cli::parse(args) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("error: {err}\n\n{}", cli::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match command {
+        Command::Run(args) => run(args),
+        Command::Bench {
+            run: args,
+            config,
+            save_baseline,
+        } => bench(args, config, save_baseline),
+        Command::Alloc(args) => alloc(args),
+        Command::New { day } => new(day),
+        Command::Watch { day } => watch(day),
+        Command::Fetch { day } => fetch(day),
+        Command::Submit { day, part } => submit(day, part),
+        Command::Extract {
+            day,
+            from,
+            block,
+            name,
+        } => extract(day, from, block, name),
+        Command::Generate { day, size, seed } => generate(day, size, seed),
+        Command::Scale { day, parts, config } => scale(day, &parts, &config),
+    };
+
+    result.unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        ExitCode::FAILURE
+    })
 }
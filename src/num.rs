@@ -0,0 +1,18 @@
+//! Number theory helpers
+
+/// Divisors of `n` smaller than `n`, in increasing order
+pub fn proper_divisors(n: usize) -> Vec<usize> {
+    (1..=n / 2).filter(|i| n.is_multiple_of(*i)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proper_divisors() {
+        assert_eq!(proper_divisors(12), vec![1, 2, 3, 4, 6]);
+        assert_eq!(proper_divisors(7), vec![1]);
+        assert!(proper_divisors(1).is_empty());
+    }
+}
@@ -0,0 +1,174 @@
+//! Parse errors pointing at the offending input, and helpers to produce them
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::grid::Grid;
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+/// Error of a day's parser, located in the input it was given
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column of the offending text, in chars
+    pub column: usize,
+    /// Whole line containing the offending text
+    pub text: String,
+    /// Length of the offending text, in chars
+    pub len: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error about `span`, which must be a slice of `input`
+    pub fn at(day: u8, input: &str, span: &str, message: impl Into<String>) -> Self {
+        let offset = (span.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        assert!(
+            offset <= input.len() && offset + span.len() <= input.len(),
+            "span is not part of the input"
+        );
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let text = &input[line_start..line_end];
+
+        ParseError {
+            day,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: text.to_string(),
+            // Only underline up to the end of the line
+            len: span.lines().next().unwrap_or("").chars().count(),
+            message: message.into(),
+        }
+    }
+
+    /// Error about missing input after the end of `input`
+    pub fn at_end(day: u8, input: &str, message: impl Into<String>) -> Self {
+        Self::at(day, input, &input[input.len()..], message)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let padding = " ".repeat(self.column - 1);
+        let carets = "^".repeat(self.len.max(1));
+
+        writeln!(f, "{}", self.message)?;
+        writeln!(
+            f,
+            "{gutter}--> day {:02}, line {}, column {}",
+            self.day, self.line, self.column
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{gutter} | {padding}{carets}")
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse `span` of `input` as a number
+pub fn number<T>(day: u8, input: &str, span: &str) -> ParseResult<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    span.parse()
+        .map_err(|err| ParseError::at(day, input, span, format!("invalid number {span:?}: {err}")))
+}
+
+/// Parse a rectangular grid of `allowed` chars
+pub fn grid(day: u8, input: &str, allowed: &[char]) -> ParseResult<Grid<char>> {
+    let mut width = None;
+
+    let rows = input
+        .lines()
+        .map(|line| {
+            if let Some((i, c)) = line.char_indices().find(|(_, c)| !allowed.contains(c)) {
+                return Err(ParseError::at(
+                    day,
+                    input,
+                    &line[i..i + c.len_utf8()],
+                    format!("unexpected {c:?}, expected one of {allowed:?}"),
+                ));
+            }
+
+            let row: Vec<char> = line.chars().collect();
+
+            match width {
+                Some(width) if width != row.len() => Err(ParseError::at(
+                    day,
+                    input,
+                    line,
+                    format!(
+                        "expected {width} columns like the first row, found {}",
+                        row.len()
+                    ),
+                )),
+                _ => {
+                    width = Some(row.len());
+                    Ok(row)
+                }
+            }
+        })
+        .collect::<ParseResult<_>>()?;
+
+    Ok(Grid::from_rows(rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_location() {
+        let input = "3-5\n10-1x\n";
+        let span = &input[7..9];
+        let result = ParseError::at(5, input, span, "invalid number");
+
+        assert_eq!(result.line, 2);
+        assert_eq!(result.column, 4);
+        assert_eq!(result.text, "10-1x");
+        assert_eq!(result.len, 2);
+    }
+
+    #[test]
+    fn test_caret_diagnostic() {
+        let expected = "invalid number \"1x\": invalid digit found in string\n \
+                        --> day 05, line 2, column 4\n  \
+                        |\n\
+                        2 | 10-1x\n  \
+                        |    ^^";
+        let input = "3-5\n10-1x";
+        let result = number::<u64>(5, input, &input[7..]).unwrap_err();
+
+        assert_eq!(result.to_string(), expected)
+    }
+
+    #[test]
+    fn test_grid() {
+        let expected = Grid::from_rows(vec![vec!['.', '@'], vec!['@', '.']]);
+        let result = grid(4, ".@\n@.\n", &['.', '@']);
+
+        assert_eq!(result, Ok(expected));
+        assert_eq!(grid(4, ".@\n@x\n", &['.', '@']).unwrap_err().column, 2);
+        assert_eq!(grid(4, ".@\n@\n", &['.', '@']).unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn test_error_at_end() {
+        let result = ParseError::at_end(6, "1 2\n3 4\n", "missing operations line");
+
+        assert_eq!((result.line, result.column, result.len), (3, 1, 0));
+    }
+}
@@ -0,0 +1,69 @@
+//! Fixed size thread pool over std threads
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+/// Apply `f` to every item on up to `jobs` threads, keeping the order of the items
+pub fn map<T, R, F>(items: &[T], jobs: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let (tx, next, f) = (tx.clone(), &next, &f);
+
+            // Workers pick the next item as soon as they are done with one
+            scope.spawn(move || {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(i) else {
+                        break;
+                    };
+
+                    tx.send((i, f(item))).unwrap();
+                }
+            });
+        }
+    });
+    drop(tx);
+
+    let mut results: Vec<_> = rx.into_iter().collect();
+    results.sort_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn test_map_keeps_order() {
+        let items: Vec<u64> = (0..20).collect();
+        let result = map(&items, 4, |&i| {
+            // Make early items finish last
+            thread::sleep(Duration::from_millis(20 - i));
+            i * i
+        });
+
+        assert_eq!(result, items.iter().map(|i| i * i).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_map_empty() {
+        let result = map(&[] as &[u8], 4, |&i| i);
+
+        assert!(result.is_empty())
+    }
+}
@@ -0,0 +1,57 @@
+//! Inclusive ranges of integers
+
+/// Range of integers from `lower` to `upper`, both included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Range {
+    pub lower: u64,
+    pub upper: u64,
+}
+
+impl Range {
+    pub fn new(lower: u64, upper: u64) -> Self {
+        Range { lower, upper }
+    }
+
+    pub fn contains(&self, n: u64) -> bool {
+        n >= self.lower && n <= self.upper
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lower > self.upper
+    }
+
+    /// Number of integers in the range
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.upper - self.lower + 1
+        }
+    }
+
+    /// Smallest range covering both ranges, if they overlap
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        let overlaps = self.lower.max(other.lower) <= self.upper.min(other.upper);
+
+        overlaps.then_some(Range {
+            lower: self.lower.min(other.lower),
+            upper: self.upper.max(other.upper),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union() {
+        let range = Range::new(3, 5);
+
+        assert_eq!(range.union(&Range::new(5, 8)), Some(Range::new(3, 8)));
+        assert_eq!(range.union(&Range::new(4, 4)), Some(range));
+        assert_eq!(range.union(&Range::new(6, 8)), None);
+        assert_eq!(range.len(), 3);
+        assert_eq!(Range::new(2, 1).len(), 0);
+    }
+}
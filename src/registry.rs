@@ -0,0 +1,161 @@
+use std::any::Any;
+
+use crate::{
+    Answer, ParseResult, Part, Solution,
+    input::{self, Policy},
+    rng::Rng,
+};
+
+type AnyInput = Box<dyn Any + Send + Sync>;
+
+/// Type erased [`Solution`], so the runner can drive every day the same way
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    policy: Policy,
+    parse: fn(&str) -> ParseResult<AnyInput>,
+    part_one: fn(&AnyInput) -> Answer,
+    part_two: fn(&AnyInput) -> Answer,
+    alternatives: fn() -> Vec<(&'static str, Part)>,
+    alternative: fn(&AnyInput, Part, &str) -> Option<Answer>,
+    generator: Option<fn(&mut Rng, usize) -> String>,
+}
+
+impl Day {
+    pub fn new<S>() -> Self
+    where
+        S: Solution,
+        S::Input: Send + Sync,
+    {
+        Day {
+            number: S::DAY,
+            policy: S::INPUT_POLICY,
+            parse: |input| Ok(Box::new(S::parse(input)?)),
+            part_one: |input| S::part_one(downcast::<S>(input)),
+            part_two: |input| S::part_two(downcast::<S>(input)),
+            alternatives: || {
+                S::ALTERNATIVES
+                    .iter()
+                    .map(|alt| (alt.name, alt.part))
+                    .collect()
+            },
+            alternative: |input, part, name| {
+                let alt = S::ALTERNATIVES
+                    .iter()
+                    .find(|alt| alt.part == part && alt.name == name)?;
+                Some((alt.solve)(downcast::<S>(input)))
+            },
+            generator: S::GENERATOR,
+        }
+    }
+
+    /// Names and parts of the alternative implementations
+    pub fn alternatives(&self) -> Vec<(&'static str, Part)> {
+        (self.alternatives)()
+    }
+
+    /// Random valid input with about `size` lines, if the day has a generator
+    pub fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        self.generator.map(|generate| generate(rng, size))
+    }
+
+    /// Parse the raw input, after preparing it according to the day's policy
+    pub fn parse(&self, input: &str) -> ParseResult<Parsed> {
+        let input = input::normalize(input, self.policy);
+
+        Ok(Parsed {
+            day: *self,
+            input: (self.parse)(&input)?,
+        })
+    }
+}
+
+fn downcast<S: Solution>(input: &AnyInput) -> &S::Input {
+    input.downcast_ref().expect("input parsed by the same day")
+}
+
+/// Parsed input of a [`Day`], ready to be solved
+pub struct Parsed {
+    day: Day,
+    input: AnyInput,
+}
+
+impl Parsed {
+    pub fn solve(&self, part: Part) -> Answer {
+        match part {
+            Part::One => (self.day.part_one)(&self.input),
+            Part::Two => (self.day.part_two)(&self.input),
+        }
+    }
+
+    /// Answer of the alternative implementation of a part with the given
+    /// name, if there is one
+    pub fn solve_alternative(&self, part: Part, name: &str) -> Option<Answer> {
+        (self.day.alternative)(&self.input, part, name)
+    }
+
+    /// Answers of the alternative implementations, with their name and part
+    pub fn alternatives(&self) -> Vec<(&'static str, Part, Answer)> {
+        self.day
+            .alternatives()
+            .into_iter()
+            .filter_map(|(name, part)| Some((name, part, self.solve_alternative(part, name)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{self, day_01::Day01};
+
+    #[test]
+    fn test_solve() {
+        let expected = Answer::from(3);
+        let day = Day::new::<Day01>();
+        let parsed = day
+            .parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82")
+            .unwrap();
+        let result = parsed.solve(Part::One);
+
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_solve_alternative() {
+        let day = days::get(3).unwrap();
+        let parsed = day
+            .parse("987654321111111\n811111111111119\n234234234234278\n818181911112111")
+            .unwrap();
+
+        assert_eq!(day.alternatives(), vec![("find_joltage", Part::One)]);
+        assert_eq!(
+            parsed.solve_alternative(Part::One, "find_joltage"),
+            Some(Answer::from(357))
+        );
+        assert_eq!(parsed.solve_alternative(Part::Two, "find_joltage"), None);
+    }
+
+    /// Inputs the parsers accept must not make the parts panic
+    #[test]
+    fn test_parse_rejects_unsolvable_inputs() {
+        let error = |number, input| {
+            let error = days::get(number).unwrap().parse(input).err().unwrap();
+            (error.line, error.column)
+        };
+
+        assert_eq!(error(6, "1 \n 2\n3 \n+ "), (2, 1));
+        assert_eq!(error(6, "1\u{a0}2\n* +"), (1, 2));
+        assert_eq!(error(7, ".S.\n...\n^.."), (3, 1));
+        assert_eq!(error(7, ".S.\n...\n..^"), (3, 3));
+    }
+
+    #[test]
+    fn test_registry_is_sorted() {
+        let numbers: Vec<u8> = days::all().iter().map(|day| day.number).collect();
+
+        assert!(numbers.is_sorted());
+        assert_eq!(days::get(5).map(|day| day.number), Some(5));
+        assert!(days::get(25).is_none());
+    }
+}
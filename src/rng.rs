@@ -0,0 +1,82 @@
+//! Small seedable PRNG for generated inputs, so failures can be reproduced
+
+use std::ops::RangeInclusive;
+
+/// SplitMix64, good enough for test inputs and tiny to carry around
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform number in `0..n`, `n` must not be 0
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// Uniform number in `range`, which must not be empty
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range");
+
+        match (end - start).checked_add(1) {
+            Some(n) => start + self.below(n),
+            None => self.next_u64(),
+        }
+    }
+
+    /// `true` with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 <= p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_numbers() {
+        let expected: Vec<_> = {
+            let mut rng = Rng::new(2025);
+            (0..10).map(|_| rng.next_u64()).collect()
+        };
+        let mut rng = Rng::new(2025);
+        let result: Vec<_> = (0..10).map(|_| rng.next_u64()).collect();
+
+        assert_eq!(result, expected);
+        assert_ne!(Rng::new(2026).next_u64(), expected[0]);
+    }
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(1);
+        let mut seen = [false; 5];
+
+        for _ in 0..1000 {
+            let n = rng.range(3..=7);
+            assert!((3..=7).contains(&n));
+            seen[n as usize - 3] = true;
+        }
+
+        assert!(seen.iter().all(|&seen| seen));
+        assert_eq!(rng.range(4..=4), 4);
+        rng.range(0..=u64::MAX);
+    }
+}
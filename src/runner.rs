@@ -0,0 +1,452 @@
+use std::{
+    any::Any,
+    cell::Cell,
+    error::Error,
+    fmt::{self, Display},
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Once, mpsc},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    Answer, Part,
+    answers::{Answers, Status},
+    examples,
+    input::{Dirs, Source},
+    json::{self, Value},
+    pool,
+    registry::Day,
+    trace,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON object per line for every day and part
+    Json,
+}
+
+/// Time after which a part is given up on, in line with the 15 seconds
+/// every puzzle is meant to be solvable in
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Why a step of a day did not produce a result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Failure {
+    /// Identifier of the failure for machine-readable output
+    pub fn key(&self) -> &'static str {
+        match self {
+            Failure::Panicked(_) => "panicked",
+            Failure::TimedOut(_) => "timed-out",
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Panicked(message) => write!(f, "panicked: {message}"),
+            Failure::TimedOut(timeout) => write!(f, "timed out after {timeout:?}"),
+        }
+    }
+}
+
+impl Error for Failure {}
+
+/// Name of the threads running isolated steps, whose panics are reported by the runner
+const STEP_THREAD: &str = "aoc2025-step";
+
+thread_local! {
+    static PANIC_LOCATION: Cell<Option<String>> = const { Cell::new(None) };
+}
+
+/// Keep the default hook from printing the panics of isolated steps, only
+/// recording where they happened
+fn install_panic_hook() {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() == Some(STEP_THREAD) {
+                PANIC_LOCATION.set(info.location().map(ToString::to_string));
+            } else {
+                default(info);
+            }
+        }));
+    });
+}
+
+/// Message of a caught panic, with where it happened for isolated steps
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    let message = match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic payload".to_string(),
+        },
+    };
+
+    match PANIC_LOCATION.take() {
+        Some(location) => format!("{message} at {location}"),
+        None => message,
+    }
+}
+
+/// Run `f` on its own thread, catching its panics and giving up on it after
+/// `timeout`
+///
+/// A thread that timed out cannot be stopped, it is left running in the
+/// background until the process exits.
+fn isolate<R, F>(timeout: Duration, f: F) -> (Result<R, Failure>, Duration)
+where
+    R: Send + 'static,
+    F: FnOnce() -> R + Send + 'static,
+{
+    install_panic_hook();
+
+    let (tx, rx) = mpsc::channel();
+
+    let spawned = thread::Builder::new()
+        .name(STEP_THREAD.to_string())
+        .spawn(move || {
+            let start = Instant::now();
+            let result = panic::catch_unwind(AssertUnwindSafe(f)).map_err(panic_message);
+            let _ = tx.send((result, start.elapsed()));
+        });
+
+    if let Err(err) = spawned {
+        let message = format!("failed to spawn thread: {err}");
+        return (Err(Failure::Panicked(message)), Duration::ZERO);
+    }
+
+    match rx.recv_timeout(timeout) {
+        Ok((result, time)) => (result.map_err(Failure::Panicked), time),
+        Err(mpsc::RecvTimeoutError::Timeout) => (Err(Failure::TimedOut(timeout)), timeout),
+        Err(mpsc::RecvTimeoutError::Disconnected) => (
+            Err(Failure::Panicked("worker thread died".to_string())),
+            Duration::ZERO,
+        ),
+    }
+}
+
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Result<Answer, Failure>,
+    /// Status against the known answers, not checked for arbitrary files
+    pub status: Option<Status>,
+    pub time: Duration,
+}
+
+pub struct DayReport {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    pub fn time(&self, part: Part) -> Option<Duration> {
+        self.parts.iter().find(|p| p.part == part).map(|p| p.time)
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.parts.iter().map(|p| p.time).sum::<Duration>()
+    }
+
+    pub fn regressions(&self) -> usize {
+        self.parts
+            .iter()
+            .filter(|p| p.status.as_ref().is_some_and(Status::is_regression))
+            .count()
+    }
+
+    /// Number of parts that panicked or timed out
+    pub fn failures(&self) -> usize {
+        self.parts.iter().filter(|p| p.answer.is_err()).count()
+    }
+}
+
+pub type RunResult = Result<DayReport, Box<dyn Error + Send + Sync>>;
+
+/// Read the input of a day and solve the requested parts, timing the parse
+/// step and each part separately
+///
+/// Solutions are checked against the known answers of the puzzle input,
+/// or the expected answers of an example. Every step runs isolated from the
+/// others, a panic or a step taking longer than `timeout` only fails that
+/// step, or the whole day for the parse step.
+pub fn run(
+    day: &Day,
+    source: &Source,
+    dirs: &Dirs,
+    parts: &[Part],
+    timeout: Duration,
+) -> RunResult {
+    let answers = match source {
+        Source::Puzzle => Some(Answers::load(&dirs.answers, day.number)?),
+        Source::Example(name) => {
+            let example = examples::name(day.number, name.as_deref());
+            Some(examples::answers(&dirs.examples, &example)?)
+        }
+        Source::File(_) | Source::Stdin => None,
+    };
+
+    let input = source.read(day.number, dirs)?;
+
+    let day = *day;
+    let (parsed, parse_time) = isolate(timeout, move || {
+        trace::scoped(day.number, || day.parse(&input))
+    });
+    let parsed = Arc::new(parsed.map_err(|failure| format!("parse step {failure}"))??);
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let parsed = Arc::clone(&parsed);
+            let (answer, time) = isolate(timeout, move || {
+                trace::scoped(day.number, || parsed.solve(part))
+            });
+
+            let status = match (&answers, &answer) {
+                (Some(answers), Ok(answer)) => Some(answers.check(part, answer)),
+                _ => None,
+            };
+
+            PartReport {
+                part,
+                answer,
+                status,
+                time,
+            }
+        })
+        .collect();
+
+    Ok(DayReport {
+        day: day.number,
+        parse_time,
+        parts,
+    })
+}
+
+/// Run several days on up to `jobs` threads, returning the results in day order
+pub fn run_all(
+    days: &[Day],
+    source: &Source,
+    dirs: &Dirs,
+    parts: &[Part],
+    jobs: usize,
+    timeout: Duration,
+) -> Vec<RunResult> {
+    pool::map(days, jobs, |day| run(day, source, dirs, parts, timeout))
+}
+
+pub fn print_answers(report: &DayReport) {
+    for PartReport {
+        part,
+        answer,
+        status,
+        ..
+    } in &report.parts
+    {
+        match (answer, status) {
+            (Ok(answer), Some(status)) => println!("Part {part} solution is {answer} ({status})"),
+            (Ok(answer), None) => println!("Part {part} solution is {answer}"),
+            (Err(failure), _) => println!("Part {part} {failure}"),
+        }
+    }
+}
+
+/// JSON object of a solved part
+pub fn part_json(report: &DayReport, part: &PartReport) -> String {
+    json::object(vec![
+        ("day", report.day.into()),
+        ("part", part.part.to_string().into()),
+        (
+            "answer",
+            part.answer
+                .as_ref()
+                .ok()
+                .map(|a| a.to_string().into())
+                .into(),
+        ),
+        (
+            "status",
+            match &part.answer {
+                Ok(_) => part.status.as_ref().map(|s| s.key().into()).into(),
+                Err(failure) => failure.key().into(),
+            },
+        ),
+        (
+            "status_detail",
+            part.status.as_ref().map(|s| s.to_string().into()).into(),
+        ),
+        ("parse_ns", report.parse_time.as_nanos().into()),
+        ("time_ns", part.time.as_nanos().into()),
+        (
+            "error",
+            part.answer
+                .as_ref()
+                .err()
+                .map(|f| f.to_string().into())
+                .into(),
+        ),
+    ])
+}
+
+/// JSON object of a part that could not be solved
+pub fn error_json(day: u8, part: Part, error: &str) -> String {
+    json::object(vec![
+        ("day", day.into()),
+        ("part", part.to_string().into()),
+        ("answer", Value::Null),
+        ("status", Value::Null),
+        ("status_detail", Value::Null),
+        ("parse_ns", Value::Null),
+        ("time_ns", Value::Null),
+        ("error", error.into()),
+    ])
+}
+
+fn format_time(time: Option<Duration>) -> String {
+    time.map_or_else(|| "-".to_string(), |time| format!("{time:.2?}"))
+}
+
+pub fn print_timings(reports: &[DayReport]) {
+    println!(
+        "{:<5}{:>12}{:>12}{:>12}{:>12}",
+        "Day", "Parse", "Part one", "Part two", "Total"
+    );
+
+    for report in reports {
+        println!(
+            "{:<5}{:>12}{:>12}{:>12}{:>12}",
+            format!("{:02}", report.day),
+            format_time(Some(report.parse_time)),
+            format_time(report.time(Part::One)),
+            format_time(report.time(Part::Two)),
+            format_time(Some(report.total_time())),
+        );
+    }
+}
+
+/// Print one row per day and part, with the time of the parse step as its own row
+pub fn print_summary(days: &[Day], results: &[RunResult], wall_time: Duration) {
+    let row = |day: u8, part: &str, answer: &str, status: &str, time: &str| {
+        println!("{day:02}   {part:<7}{answer:<20}{status:<36}{time:>12}");
+    };
+
+    println!(
+        "{:<5}{:<7}{:<20}{:<36}{:>12}",
+        "Day", "Part", "Answer", "Status", "Time"
+    );
+
+    let mut total = Duration::ZERO;
+
+    for (day, result) in days.iter().zip(results) {
+        match result {
+            Ok(report) => {
+                row(
+                    report.day,
+                    "parse",
+                    "",
+                    "",
+                    &format_time(Some(report.parse_time)),
+                );
+
+                for part in &report.parts {
+                    let (answer, status) = match (&part.answer, &part.status) {
+                        (Ok(answer), Some(status)) => (answer.to_string(), status.to_string()),
+                        (Ok(answer), None) => (answer.to_string(), "-".to_string()),
+                        (Err(failure), _) => (String::new(), failure.to_string()),
+                    };
+                    row(
+                        report.day,
+                        &part.part.to_string(),
+                        &answer,
+                        &status,
+                        &format_time(Some(part.time)),
+                    );
+                }
+
+                total += report.total_time();
+            }
+            Err(_) => row(day.number, "-", "", "error", "-"),
+        }
+    }
+
+    println!("{:<68}{:>12}", "Total", format_time(Some(total)));
+    println!("{:<68}{:>12}", "Wall clock", format_time(Some(wall_time)));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::{days, input::scratch_path};
+
+    #[test]
+    fn test_run_times_each_phase() {
+        let dir = scratch_path("run");
+        let dirs = Dirs::under(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input.txt");
+        fs::write(&path, "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82").unwrap();
+
+        let day = days::get(1).unwrap();
+        let report = run(
+            &day,
+            &Source::File(path.clone()),
+            &dirs,
+            &[Part::Two],
+            DEFAULT_TIMEOUT,
+        )
+        .unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(report.parts.len(), 1);
+        assert_eq!(report.parts[0].answer, Ok(Answer::from(6)));
+        assert!(report.parts[0].status.is_none());
+        assert!(report.time(Part::One).is_none());
+        assert_eq!(
+            report.total_time(),
+            report.parse_time + report.time(Part::Two).unwrap()
+        );
+
+        let json = part_json(&report, &report.parts[0]);
+        assert!(json.starts_with(r#"{"day":1,"part":"two","answer":"6","status":null,"#));
+        assert!(json.ends_with(r#","error":null}"#));
+    }
+
+    #[test]
+    fn test_isolate() {
+        let (result, _) = isolate(DEFAULT_TIMEOUT, || 42);
+        assert_eq!(result, Ok(42));
+
+        let (result, _) = isolate(DEFAULT_TIMEOUT, || -> u8 { panic!("day {} broke", 6) });
+        assert!(
+            matches!(&result, Err(Failure::Panicked(message)) if message.starts_with("day 6 broke at src/runner.rs:")),
+            "unexpected {result:?}"
+        );
+
+        let timeout = Duration::from_millis(10);
+        let (result, _) = isolate(timeout, || thread::sleep(Duration::from_secs(1)));
+        assert_eq!(result, Err(Failure::TimedOut(timeout)));
+    }
+
+    #[test]
+    fn test_error_json() {
+        let expected = r#"{"day":5,"part":"one","answer":null,"status":null,"status_detail":null,"parse_ns":null,"time_ns":null,"error":"input file missing"}"#;
+        let result = error_json(5, Part::One, "input file missing");
+
+        assert_eq!(result, expected)
+    }
+}
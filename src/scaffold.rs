@@ -0,0 +1,220 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::examples;
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+fn render(day: u8) -> String {
+    TEMPLATE
+        .replace("{{day}}", &format!("{day:02}"))
+        .replace("{{number}}", &day.to_string())
+}
+
+/// Day number of a line like `pub mod day_05;` or `Day::new::<day_05::Day05>(),`
+fn registered_day(line: &str, prefix: &str) -> Option<u8> {
+    let rest = line.trim_start().strip_prefix(prefix)?;
+    rest.get(..2)?.parse().ok()
+}
+
+/// Insert `new` among the lines starting with `prefix`, keeping them sorted by day
+fn insert_sorted(lines: &mut Vec<String>, prefix: &str, day: u8, new: String) -> io::Result<()> {
+    let registered: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, registered_day(line, prefix)?)))
+        .collect();
+
+    let Some(&(last, _)) = registered.last() else {
+        return Err(io::Error::new(
+            ErrorKind::InvalidData,
+            format!("no lines starting with {prefix:?} to register the day next to"),
+        ));
+    };
+
+    let idx = registered
+        .iter()
+        .find(|&&(_, other)| other > day)
+        .map_or(last + 1, |&(i, _)| i);
+
+    // Reuse the indentation of the neighbouring lines
+    let indent: String = lines[last]
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect();
+    lines.insert(idx, indent + &new);
+
+    Ok(())
+}
+
+fn register(days_mod: &Path, day: u8) -> io::Result<()> {
+    let source = fs::read_to_string(days_mod)?;
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+
+    insert_sorted(
+        &mut lines,
+        "pub mod day_",
+        day,
+        format!("pub mod day_{day:02};"),
+    )?;
+    insert_sorted(
+        &mut lines,
+        "Day::new::<day_",
+        day,
+        format!("Day::new::<day_{day:02}::Day{day:02}>(),"),
+    )?;
+
+    fs::write(days_mod, lines.join("\n") + "\n")
+}
+
+/// Create an empty example and placeholder expected answers to fill in,
+/// so the generated example tests fail until the day is solved
+fn add_example(examples_dir: &Path, day: u8) -> io::Result<()> {
+    fs::create_dir_all(examples_dir)?;
+
+    let example = examples::name(day, None);
+    let path = examples_dir.join(format!("{example}.txt"));
+    if !path.exists() {
+        fs::write(path, "")?;
+    }
+
+    let manifest = examples_dir.join(examples::MANIFEST);
+    let content = match fs::read_to_string(&manifest) {
+        Ok(content) => content,
+        Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err),
+    };
+
+    if content
+        .lines()
+        .any(|line| line.split_whitespace().next() == Some(example.as_str()))
+    {
+        return Ok(());
+    }
+
+    let mut file = OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(manifest)?;
+    if !content.is_empty() && !content.ends_with('\n') {
+        writeln!(file)?;
+    }
+    writeln!(file, "{example} one 0\n{example} two 0")
+}
+
+/// Generate the module of a new day under `root`, register it in the runner
+/// and add its example skeleton
+///
+/// Refuses to touch anything if the day module already exists.
+pub fn scaffold(root: &Path, day: u8) -> io::Result<PathBuf> {
+    let days_dir = root.join("src").join("days");
+    let path = days_dir.join(format!("day_{day:02}.rs"));
+
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .map_err(|err| match err.kind() {
+            ErrorKind::AlreadyExists => io::Error::new(
+                ErrorKind::AlreadyExists,
+                format!("{} already exists", path.display()),
+            ),
+            _ => err,
+        })?;
+
+    file.write_all(render(day).as_bytes())?;
+
+    if let Err(err) = register(&days_dir.join("mod.rs"), day) {
+        // Do not leave an unregistered module behind
+        fs::remove_file(&path)?;
+        return Err(err);
+    }
+
+    add_example(&root.join("examples"), day)?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::scratch_path;
+
+    const DAYS_MOD: &str = "\
+pub mod day_01;
+pub mod day_03;
+
+use crate::registry::Day;
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<day_01::Day01>(),
+        Day::new::<day_03::Day03>(),
+    ]
+}
+";
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = scratch_path(name);
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src").join("days")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), DAYS_MOD).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = temp_root("scaffold");
+
+        let path = scaffold(&root, 2).unwrap();
+        let module = fs::read_to_string(path).unwrap();
+        let days_mod = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+
+        assert!(module.contains("pub struct Day02;"));
+        assert!(module.contains("const DAY: u8 = 2;"));
+        assert!(days_mod.contains("pub mod day_01;\npub mod day_02;\npub mod day_03;\n"));
+        assert!(days_mod.contains("        Day::new::<day_02::Day02>(),\n"));
+
+        let example = fs::read_to_string(root.join("examples/day_02.txt")).unwrap();
+        let manifest = fs::read_to_string(root.join("examples/expected.txt")).unwrap();
+
+        assert_eq!(example, "");
+        assert_eq!(manifest, "day_02 one 0\nday_02 two 0\n");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_scaffold_ignores_named_examples() {
+        let root = temp_root("named");
+        fs::create_dir_all(root.join("examples")).unwrap();
+        fs::write(root.join("examples/expected.txt"), "day_02_big one 7\n").unwrap();
+
+        scaffold(&root, 2).unwrap();
+        let manifest = fs::read_to_string(root.join("examples/expected.txt")).unwrap();
+        fs::remove_dir_all(root).unwrap();
+
+        assert_eq!(manifest, "day_02_big one 7\nday_02 one 0\nday_02 two 0\n");
+    }
+
+    #[test]
+    fn test_scaffold_refuses_overwrite() {
+        let root = temp_root("overwrite");
+        let existing = root.join("src/days/day_03.rs");
+        fs::write(&existing, "// solved").unwrap();
+
+        let result = scaffold(&root, 3);
+
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::AlreadyExists);
+        assert_eq!(fs::read_to_string(existing).unwrap(), "// solved");
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/mod.rs")).unwrap(),
+            DAYS_MOD
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,147 @@
+//! Empirical complexity of a day, from its timings on generated inputs of
+//! geometrically growing size
+//!
+//! The size `n` is the length of the input in bytes, so exponents compare
+//! across days whatever the shape of their input.
+
+use std::time::Duration;
+
+use crate::{
+    Part,
+    bench::{self, Phase, Stats},
+    differential,
+    registry::Day,
+    rng::Rng,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Generator size of the first input, doubled at every step
+    pub start: usize,
+    pub steps: usize,
+    /// Stop growing the input once a phase takes longer than this
+    pub limit: Duration,
+    pub seed: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            start: 10,
+            steps: 10,
+            limit: Duration::from_secs(1),
+            seed: differential::DEFAULT_SEED,
+        }
+    }
+}
+
+/// Slope of the least squares line through `(n, time)` points in log-log
+/// space, the exponent of the complexity, `None` without two distinct sizes
+///
+/// Points with a zero time, too fast for the clock, have no logarithm and
+/// are left out.
+pub fn slope(points: &[(f64, f64)]) -> Option<f64> {
+    let logs: Vec<(f64, f64)> = points
+        .iter()
+        .filter(|&&(n, t)| n > 0.0 && t > 0.0)
+        .map(|&(n, t)| (n.ln(), t.ln()))
+        .collect();
+    let count = logs.len() as f64;
+    let mean_x = logs.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let mean_y = logs.iter().map(|(_, y)| y).sum::<f64>() / count;
+
+    let covariance: f64 = logs.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let variance: f64 = logs.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+
+    (variance > 0.0).then(|| covariance / variance)
+}
+
+/// Median time of `f`, run a few times
+fn time<T>(f: impl FnMut() -> T) -> Duration {
+    let config = bench::Config {
+        warmup: Duration::ZERO,
+        samples: 3,
+        sample_time: Duration::ZERO,
+    };
+
+    Stats::from_samples(&bench::sample(&config, f)).median
+}
+
+/// Time the parse step and the parts on growing inputs, printing the times
+/// of every input, then the estimated complexity of every phase
+pub fn scale(day: &Day, parts: &[Part], config: &Config) -> Result<(), String> {
+    let phases: Vec<Phase> = [Phase::Parse]
+        .into_iter()
+        .chain(parts.iter().map(|&part| Phase::Part(part)))
+        .collect();
+
+    print!("{:<10}{:>12}", "Size", "Bytes");
+    for phase in &phases {
+        print!("{:>12}", phase.to_string());
+    }
+    println!();
+
+    let mut points: Vec<Vec<(f64, f64)>> = vec![Vec::new(); phases.len()];
+
+    for step in 0..config.steps {
+        let size = config.start << step;
+        let input = day
+            .generate(&mut Rng::new(config.seed), size)
+            .ok_or_else(|| format!("day {} has no input generator", day.number))?;
+        let parsed = day
+            .parse(&input)
+            .map_err(|err| format!("generated input of size {size} does not parse\n{err}"))?;
+
+        let times: Vec<Duration> = phases
+            .iter()
+            .map(|phase| match phase {
+                Phase::Parse => time(|| day.parse(&input)),
+                Phase::Part(part) => time(|| parsed.solve(*part)),
+                Phase::Alternative(part, name) => time(|| parsed.solve_alternative(*part, name)),
+            })
+            .collect();
+
+        print!("{size:<10}{:>12}", input.len());
+        for (time, points) in times.iter().zip(&mut points) {
+            print!("{:>12}", format!("{time:.2?}"));
+            points.push((input.len() as f64, time.as_secs_f64()));
+        }
+        println!();
+
+        if times.iter().any(|&time| time > config.limit) {
+            break;
+        }
+    }
+
+    println!();
+    for (phase, points) in phases.iter().zip(&points) {
+        // Small inputs are dominated by constant costs, only fit the larger half
+        let larger = &points[points.len() / 2..];
+        let fitted = if larger.len() >= 3 { larger } else { points };
+
+        match slope(fitted) {
+            Some(exponent) => println!("{:<9}≈ O(n^{exponent:.1})", phase.key()),
+            None => println!("{:<9}not enough sizes to estimate", phase.key()),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slope() {
+        let quadratic: Vec<_> = (1..10)
+            .map(|n| (n as f64 * 100.0, 3e-9 * (n as f64 * 100.0).powi(2)))
+            .collect();
+        let result = slope(&quadratic).unwrap();
+
+        assert!((result - 2.0).abs() < 1e-9, "slope {result}");
+        assert_eq!(slope(&[(10.0, 1.0), (10.0, 2.0)]), None);
+        assert_eq!(slope(&[(10.0, 0.0), (20.0, 2.0)]), None);
+        assert_eq!(slope(&[]), None);
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt::{self, Display};
+
+use crate::{answer::Answer, input::Policy, parse::ParseResult, rng::Rng};
+
+/// Common interface implemented by every day
+pub trait Solution {
+    const DAY: u8;
+
+    /// How the raw input is prepared before being handed to [`Solution::parse`]
+    const INPUT_POLICY: Policy = Policy::TrimNewline;
+
+    /// Puzzle input after the parse step, shared by both parts
+    type Input: 'static;
+
+    /// Parse the puzzle input, pointing at the offending text on failure
+    fn parse(input: &str) -> ParseResult<Self::Input>;
+
+    fn part_one(input: &Self::Input) -> Answer;
+
+    fn part_two(input: &Self::Input) -> Answer;
+
+    /// Other implementations of the parts, checked against [`Solution::part_one`]
+    /// and [`Solution::part_two`] by the differential tests
+    const ALTERNATIVES: &'static [Alternative<Self::Input>] = &[];
+
+    /// Random valid input with about `size` lines, for the differential tests,
+    /// stress tests and scaling studies
+    const GENERATOR: Option<fn(&mut Rng, usize) -> String> = None;
+}
+
+/// Another implementation of a part, expected to give the same answers
+pub struct Alternative<I> {
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: fn(&I) -> Answer,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "one"),
+            Part::Two => write!(f, "two"),
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! Submit answers to the puzzle website and record what it said about them
+//!
+//! Answers are computed on the puzzle input, and only submitted when nothing
+//! is known about them yet, so a guess already known to be wrong or outside
+//! the known bounds never costs a rate-limited attempt.
+
+use std::{
+    fmt::{self, Display},
+    time::Duration,
+};
+
+use crate::{
+    Answer, Part,
+    answers::{Answers, Entry, Status, Verdict},
+    client::{self, Config, YEAR},
+    input::{Dirs, Source},
+    registry::Day,
+    runner,
+};
+
+/// What the website said about a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted too soon after the previous answer, nothing was checked
+    Wait(Duration),
+    /// The part was already solved, nothing was checked
+    AlreadySolved,
+}
+
+impl Outcome {
+    /// Read the outcome from the text of the response page
+    pub fn parse(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if page.contains("your answer is too high") {
+            Some(Outcome::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Outcome::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Some(Outcome::Wrong)
+        } else if page.contains("You gave an answer too recently") {
+            Some(Outcome::Wait(
+                wait_time(page).unwrap_or(Duration::from_secs(60)),
+            ))
+        } else if page.contains("Did you already complete it") {
+            Some(Outcome::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    /// Verdict to record in the answers registry, if the answer was checked
+    pub fn verdict(&self) -> Option<Verdict> {
+        match self {
+            Outcome::Correct => Some(Verdict::Confirmed),
+            Outcome::TooHigh => Some(Verdict::TooHigh),
+            Outcome::TooLow => Some(Verdict::TooLow),
+            Outcome::Wrong => Some(Verdict::Wrong),
+            Outcome::Wait(_) | Outcome::AlreadySolved => None,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait(wait) => write!(f, "too soon, wait {}s", wait.as_secs()),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Time left to wait in "You have 1m 23s left to wait."
+fn wait_time(page: &str) -> Option<Duration> {
+    let (before, _) = page.split_once(" left to wait")?;
+    let (_, left) = before.rsplit_once("You have ")?;
+
+    left.split_whitespace()
+        .map(|amount| {
+            let (n, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let n: u64 = n.parse().ok()?;
+            match unit {
+                "h" => Some(n * 3600),
+                "m" => Some(n * 60),
+                "s" => Some(n),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Percent-encode a form value
+fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// Post an answer, without any check
+pub fn post(config: &Config, day: u8, part: Part, answer: &Answer) -> Result<Outcome, String> {
+    let level = match part {
+        Part::One => 1,
+        Part::Two => 2,
+    };
+    let form = format!("level={level}&answer={}", encode(&answer.to_string()));
+
+    let response = client::request(config, &format!("/{YEAR}/day/{day}/answer"), Some(&form))?;
+    if response.status != 200 {
+        return Err(format!(
+            "unexpected response {} to the submission",
+            response.status
+        ));
+    }
+
+    Outcome::parse(&response.body)
+        .ok_or_else(|| "could not find the outcome in the response page".to_string())
+}
+
+/// Solve a part on the puzzle input and submit the answer, unless the known
+/// answers already say it is wrong, then record the outcome
+pub fn submit(
+    config: &Config,
+    day: &Day,
+    part: Part,
+    dirs: &Dirs,
+    timeout: Duration,
+) -> Result<(Answer, Outcome), String> {
+    let report =
+        runner::run(day, &Source::Puzzle, dirs, &[part], timeout).map_err(|err| err.to_string())?;
+    let report = &report.parts[0];
+    let answer = report
+        .answer
+        .clone()
+        .map_err(|failure| format!("part {part} {failure}"))?;
+
+    match &report.status {
+        Some(Status::New) | None => {}
+        Some(Status::Confirmed) => {
+            return Err(format!("{answer} is already confirmed, nothing to submit"));
+        }
+        Some(status) => return Err(format!("refusing to submit {answer}: {status}")),
+    }
+
+    let outcome = post(config, day.number, part, &answer)?;
+
+    if let Some(verdict) = outcome.verdict() {
+        let entry = Entry {
+            part,
+            verdict,
+            answer: answer.clone(),
+        };
+        Answers::record(&dirs.answers, day.number, &entry)?;
+    }
+
+    Ok((answer, outcome))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::{client::mock, days, input::scratch_path};
+
+    #[test]
+    fn test_outcome() {
+        let page = |text| format!("<main>\n<article><p>{text}</p></article>\n</main>");
+
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Some(Outcome::Correct)
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer; your answer is too low. Please wait one minute."
+            )),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(
+            Outcome::parse(&page("That's not the right answer. If you're stuck, ...")),
+            Some(Outcome::Wrong)
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 2m 5s left to wait."
+            )),
+            Some(Outcome::Wait(Duration::from_secs(125)))
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Some(Outcome::AlreadySolved)
+        );
+        assert_eq!(Outcome::parse(&page("Welcome")), None);
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode("abc-123"), "abc-123");
+        assert_eq!(encode("a b&c=d"), "a%20b%26c%3Dd");
+    }
+
+    #[test]
+    fn test_submit() {
+        let dir = scratch_path("submit");
+        let dirs = Dirs::under(&dir);
+        fs::create_dir_all(&dirs.inputs).unwrap();
+        fs::write(
+            dirs.inputs.join("day_01.txt"),
+            "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n",
+        )
+        .unwrap();
+
+        let (base_url, server) = mock::serve(vec![
+            (
+                200,
+                "<p>That's not the right answer; your answer is too high.</p>",
+            ),
+            (200, "<p>That's the right answer!</p>"),
+        ]);
+        let config = Config {
+            base_url: Some(base_url),
+            session: Some("53cr37".to_string()),
+        };
+        let day = days::get(1).unwrap();
+        let submit = |part| submit(&config, &day, part, &dirs, runner::DEFAULT_TIMEOUT);
+
+        let first = submit(Part::Two);
+        // Already known too high, refused without reaching the server
+        let second = submit(Part::Two);
+        let third = submit(Part::One);
+        let requests = server.join().unwrap();
+        let answers = fs::read_to_string(Answers::path(&dirs.answers, 1));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(first, Ok((Answer::from(6), Outcome::TooHigh)));
+        assert!(second.unwrap_err().starts_with("refusing to submit 6"));
+        assert_eq!(third, Ok((Answer::from(3), Outcome::Correct)));
+        assert_eq!(answers.unwrap(), "two too-high 6\none confirmed 3\n");
+        assert!(requests[0].starts_with("POST /2025/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=6"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=1&answer=3"));
+    }
+}
@@ -0,0 +1,272 @@
+//! Leveled diagnostics of the solutions, written to stderr so the answers on
+//! stdout stay clean
+//!
+//! Records are filtered by level, globally or per day, from `AOC_LOG`, e.g.
+//! `info,day05=debug`, raised by the `-v` flags of the command line. The
+//! runner scopes every step to its day, which prefixes its records.
+
+use std::{
+    cell::Cell,
+    env,
+    fmt::{self, Arguments, Display},
+    str::FromStr,
+    sync::OnceLock,
+};
+
+/// Environment variable with the filter of the records
+pub const LOG_VAR: &str = "AOC_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    /// Level shown by default, raised by one for every `-v`
+    pub const DEFAULT: Level = Level::Warn;
+
+    const ALL: [Level; 5] = [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+
+    /// Level `steps` more verbose, saturating at [`Level::Trace`]
+    pub fn raise(self, steps: u8) -> Level {
+        Level::ALL[(self as usize + steps as usize).min(Level::ALL.len() - 1)]
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Level::ALL
+            .into_iter()
+            .find(|level| level.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("invalid log level {s:?}"))
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.pad(name)
+    }
+}
+
+/// Most verbose level shown, for all records and for the records of given days
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    pub default: Level,
+    pub days: Vec<(u8, Level)>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter {
+            default: Level::DEFAULT,
+            days: Vec::new(),
+        }
+    }
+}
+
+impl Filter {
+    /// Parse comma separated directives, either a level or `dayNN=level`
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut filter = Filter::default();
+
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((day, level)) => {
+                    let number = day
+                        .strip_prefix("day")
+                        .and_then(|number| number.parse().ok())
+                        .filter(|number| (1..=25).contains(number))
+                        .ok_or_else(|| format!("invalid day {day:?} in {LOG_VAR}"))?;
+                    filter.days.push((number, level.parse()?));
+                }
+                None => filter.default = directive.parse()?,
+            }
+        }
+
+        Ok(filter)
+    }
+
+    /// Filter from `AOC_LOG`, with every level raised by `verbosity`
+    pub fn from_env(verbosity: u8) -> Result<Self, String> {
+        Self::from_spec(env::var(LOG_VAR).ok().as_deref(), verbosity)
+    }
+
+    /// Filter from an optional spec, with every level raised by `verbosity`
+    fn from_spec(spec: Option<&str>, verbosity: u8) -> Result<Self, String> {
+        let mut filter = match spec {
+            Some(spec) => Filter::parse(spec)?,
+            None => Filter::default(),
+        };
+
+        if verbosity > 0 {
+            let verbose = Level::DEFAULT.raise(verbosity);
+            filter.default = filter.default.max(verbose);
+            for (_, level) in &mut filter.days {
+                *level = (*level).max(verbose);
+            }
+        }
+
+        Ok(filter)
+    }
+
+    pub fn enabled(&self, day: Option<u8>, level: Level) -> bool {
+        let max = day
+            .and_then(|day| self.days.iter().rev().find(|(number, _)| *number == day))
+            .map_or(self.default, |&(_, level)| level);
+
+        level <= max
+    }
+}
+
+static FILTER: OnceLock<Filter> = OnceLock::new();
+
+thread_local! {
+    static DAY: Cell<Option<u8>> = const { Cell::new(None) };
+}
+
+/// Set the filter of the whole program, only the first call has an effect
+pub fn init(filter: Filter) {
+    let _ = FILTER.set(filter);
+}
+
+/// Run `f` with the records of this thread attributed to `day`
+pub fn scoped<R>(day: u8, f: impl FnOnce() -> R) -> R {
+    let previous = DAY.replace(Some(day));
+    let result = f();
+    DAY.set(previous);
+    result
+}
+
+/// Whether records of `level` are shown in the current scope, to skip
+/// formatting the others
+pub fn enabled(level: Level) -> bool {
+    FILTER
+        .get_or_init(Filter::default)
+        .enabled(DAY.get(), level)
+}
+
+/// Line of a record, prefixed with its day and level
+fn format(day: Option<u8>, level: Level, args: Arguments) -> String {
+    match day {
+        Some(day) => format!("[day {day:02} {level:<5}] {args}"),
+        None => format!("[{level:<5}] {args}"),
+    }
+}
+
+/// Write a record to stderr, if enabled; use the macros instead
+pub fn log(level: Level, args: Arguments) {
+    if enabled(level) {
+        eprintln!("{}", format(DAY.get(), level, args));
+    }
+}
+
+/// Record a message of the given [`Level`], formatted like [`format!`] only
+/// when it is shown
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::trace::enabled($level) {
+            $crate::trace::log($level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let expected = Filter {
+            default: Level::Info,
+            days: vec![(5, Level::Trace), (1, Level::Error)],
+        };
+        let result = Filter::parse("info, day05=trace,day1=ERROR").unwrap();
+
+        assert_eq!(result, expected);
+        assert!(result.enabled(Some(5), Level::Trace));
+        assert!(result.enabled(None, Level::Info));
+        assert!(!result.enabled(Some(2), Level::Debug));
+        assert!(!result.enabled(Some(1), Level::Warn));
+
+        assert!(Filter::parse("loud").is_err());
+        assert!(Filter::parse("day26=debug").is_err());
+        assert!(Filter::parse("day5=").is_err());
+    }
+
+    #[test]
+    fn test_from_spec() {
+        let quiet = Filter::from_spec(Some("error"), 0).unwrap();
+        let raised = Filter::from_spec(Some("error,day05=info"), 1).unwrap();
+
+        assert_eq!(quiet.default, Level::Error);
+        assert!(!quiet.enabled(None, Level::Warn));
+        assert_eq!(raised.default, Level::Info);
+        assert_eq!(raised.days, vec![(5, Level::Info)]);
+        assert_eq!(Filter::from_spec(None, 0), Ok(Filter::default()));
+    }
+
+    #[test]
+    fn test_raise() {
+        assert_eq!(Level::DEFAULT.raise(0), Level::Warn);
+        assert_eq!(Level::DEFAULT.raise(2), Level::Debug);
+        assert_eq!(Level::DEFAULT.raise(9), Level::Trace);
+    }
+
+    #[test]
+    fn test_scoped_format() {
+        let result = scoped(5, || {
+            format(DAY.get(), Level::Debug, format_args!("{}", 42))
+        });
+
+        assert_eq!(result, "[day 05 DEBUG] 42");
+        assert_eq!(
+            format(DAY.get(), Level::Warn, format_args!("x")),
+            "[WARN ] x"
+        );
+    }
+}
@@ -0,0 +1,338 @@
+//! Re-run a day whenever the sources, its input or its examples change
+//!
+//! Solutions are compiled into the binary, so every run goes through
+//! `cargo run`, which rebuilds the day first when its source changed. The
+//! answers are read back from the `--format json` output of the run.
+
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    answers::Answers,
+    examples,
+    input::{Dirs, Source},
+    json::{self, Value},
+};
+
+/// Time between two checks of the watched files
+pub const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Rust sources under `dir`, recursively
+fn sources(dir: &Path, found: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for path in entries.filter_map(|entry| Some(entry.ok()?.path())) {
+        if path.is_dir() {
+            sources(&path, found);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            found.push(path);
+        }
+    }
+}
+
+/// Files the answers of a day depend on
+///
+/// Every source of the crate is watched, as a day can use any of the shared
+/// modules, so editing another day also triggers a run.
+pub fn files(root: &Path, day: u8, dirs: &Dirs) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    sources(&root.join("src"), &mut files);
+    files.sort();
+
+    files.extend([
+        dirs.inputs.join(format!("day_{day:02}.txt")),
+        Answers::path(&dirs.answers, day),
+        dirs.examples.join(examples::MANIFEST),
+    ]);
+
+    // Listed on every check, so new examples are picked up
+    if let Ok(entries) = fs::read_dir(&dirs.examples) {
+        let mut found: Vec<_> = entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| {
+                path.extension().is_some_and(|ext| ext == "txt")
+                    && path
+                        .file_stem()
+                        .and_then(|stem| examples::day(&stem.to_string_lossy()))
+                        == Some(day)
+            })
+            .collect();
+        found.sort();
+        files.extend(found);
+    }
+
+    files
+}
+
+/// Modification times of the watched files, `None` for missing files
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, Option<SystemTime>>);
+
+impl Snapshot {
+    pub fn take(files: Vec<PathBuf>) -> Self {
+        Snapshot(
+            files
+                .into_iter()
+                .map(|path| {
+                    let modified = fs::metadata(&path).and_then(|meta| meta.modified()).ok();
+                    (path, modified)
+                })
+                .collect(),
+        )
+    }
+
+    /// Files created, deleted or modified since `previous`
+    pub fn changes<'a>(&'a self, previous: &Snapshot) -> Vec<&'a Path> {
+        self.0
+            .iter()
+            .filter(|(path, modified)| previous.0.get(*path) != Some(modified))
+            .map(|(path, _)| path.as_path())
+            .collect()
+    }
+}
+
+/// Answer of a part as reported by a run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub part: String,
+    pub answer: Option<String>,
+    /// Status against the known answers, or the error
+    pub detail: Option<String>,
+}
+
+impl Outcome {
+    /// Read a line of the `--format json` output
+    pub fn parse(line: &str) -> Option<Self> {
+        let fields: BTreeMap<_, _> = json::parse_object(line)?.into_iter().collect();
+        let string = |key| match fields.get(key) {
+            Some(Value::String(s)) => Some(s.clone()),
+            _ => None,
+        };
+
+        Some(Outcome {
+            part: string("part")?,
+            answer: string("answer"),
+            detail: string("error").or_else(|| string("status_detail")),
+        })
+    }
+
+    /// Line describing the outcome, with the answer of the previous run if it changed
+    pub fn describe(&self, previous: Option<&Option<String>>) -> String {
+        let mut line = format!(
+            "Part {}: {}",
+            self.part,
+            self.answer.as_deref().unwrap_or("-")
+        );
+
+        if let Some(detail) = &self.detail {
+            line += &format!(" ({detail})");
+        }
+
+        match previous {
+            Some(previous) if *previous != self.answer => {
+                line += &format!(" [was {}]", previous.as_deref().unwrap_or("-"));
+            }
+            _ => {}
+        }
+
+        line
+    }
+}
+
+/// What to run on every change: the examples of the day, then its input
+fn targets(day: u8, dirs: &Dirs) -> Vec<(String, Source)> {
+    let mut names: Vec<String> = match examples::load_manifest(&dirs.examples) {
+        Ok(manifest) => manifest
+            .into_iter()
+            .map(|expected| expected.example)
+            .filter(|example| examples::day(example) == Some(day))
+            .collect(),
+        Err(err) => {
+            eprintln!("error: {err}");
+            Vec::new()
+        }
+    };
+    names.sort();
+    names.dedup();
+
+    let prefix = examples::name(day, None);
+    let mut targets: Vec<_> = names
+        .into_iter()
+        .map(|example| {
+            let name = example
+                .strip_prefix(&prefix)
+                .and_then(|name| name.strip_prefix('_'))
+                .map(String::from);
+            (example, Source::Example(name))
+        })
+        .collect();
+
+    targets.push(("input".to_string(), Source::Puzzle));
+    targets
+}
+
+/// Run a day through cargo, rebuilding it if needed
+fn command(root: &Path, day: u8, source: &Source) -> Command {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+
+    let mut command = Command::new(cargo);
+    command
+        .args(["run", "--quiet", "--manifest-path"])
+        .arg(root.join("Cargo.toml"));
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command.args(["--", &day.to_string(), "--format", "json"]);
+    if let Source::Example(name) = source {
+        command.arg("--example").args(name);
+    }
+
+    command.stdin(Stdio::null()).stdout(Stdio::piped());
+    command
+}
+
+type Previous = BTreeMap<(String, String), Option<String>>;
+
+/// Run the examples and the input, stopping at the first run that produced
+/// no answers, e.g. because the build failed
+fn run_once(root: &Path, day: u8, dirs: &Dirs, previous: &mut Previous) -> Result<(), String> {
+    for (label, source) in targets(day, dirs) {
+        println!("{label}");
+
+        let mut child = command(root, day, &source)
+            .spawn()
+            .map_err(|err| format!("failed to run cargo: {err}"))?;
+
+        let outcomes: Vec<_> = BufReader::new(child.stdout.take().unwrap())
+            .lines()
+            .map_while(Result::ok)
+            .filter_map(|line| Outcome::parse(&line))
+            .collect();
+        let status = child
+            .wait()
+            .map_err(|err| format!("failed to run cargo: {err}"))?;
+
+        if outcomes.is_empty() && !status.success() {
+            println!("  failed, see the errors above");
+            return Ok(());
+        }
+
+        for outcome in outcomes {
+            let key = (label.clone(), outcome.part.clone());
+            println!("  {}", outcome.describe(previous.get(&key)));
+            previous.insert(key, outcome.answer);
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the day, then run it again every time one of its files changes
+pub fn watch(root: &Path, day: u8, dirs: &Dirs) -> Result<(), String> {
+    let mut previous = Previous::new();
+    let mut snapshot = Snapshot::take(files(root, day, dirs));
+
+    println!("Watching day {day:02}, press Ctrl-C to stop\n");
+    run_once(root, day, dirs, &mut previous)?;
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let current = Snapshot::take(files(root, day, dirs));
+        let changes = current.changes(&snapshot);
+        if changes.is_empty() {
+            continue;
+        }
+
+        let changes: Vec<_> = changes
+            .iter()
+            .map(|path| {
+                path.strip_prefix(root)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string()
+            })
+            .collect();
+        println!("\n== Changed: {}\n", changes.join(", "));
+
+        snapshot = current;
+        run_once(root, day, dirs, &mut previous)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use super::*;
+    use crate::input::scratch_path;
+
+    #[test]
+    fn test_snapshot_changes() {
+        let dir = scratch_path("watch");
+        fs::create_dir_all(&dir).unwrap();
+        let (a, b) = (dir.join("a.txt"), dir.join("b.txt"));
+        fs::write(&a, "a").unwrap();
+
+        let before = Snapshot::take(vec![a.clone(), b.clone()]);
+        assert!(
+            Snapshot::take(vec![a.clone(), b.clone()])
+                .changes(&before)
+                .is_empty()
+        );
+
+        let later = SystemTime::now() + Duration::from_secs(60);
+        File::options()
+            .write(true)
+            .open(&a)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        fs::write(&b, "b").unwrap();
+        let after = Snapshot::take(vec![a.clone(), b.clone()]);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(after.changes(&before), vec![a.as_path(), b.as_path()]);
+    }
+
+    #[test]
+    fn test_files_include_shared_modules() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let dirs = Dirs::from_env();
+        let result = files(root, 5, &dirs);
+
+        assert!(result.contains(&root.join("src/days/day_05.rs")));
+        assert!(result.contains(&root.join("src/range.rs")));
+        assert!(result.contains(&dirs.inputs.join("day_05.txt")));
+    }
+
+    #[test]
+    fn test_outcome() {
+        let outcome = Outcome::parse(
+            r#"{"day":3,"part":"one","answer":"358","status":"differs","status_detail":"differs from confirmed 357","parse_ns":1,"time_ns":2,"error":null}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            outcome.describe(Some(&Some("357".to_string()))),
+            "Part one: 358 (differs from confirmed 357) [was 357]"
+        );
+        assert_eq!(
+            outcome.describe(Some(&Some("358".to_string()))),
+            "Part one: 358 (differs from confirmed 357)"
+        );
+        assert_eq!(
+            outcome.describe(None),
+            "Part one: 358 (differs from confirmed 357)"
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{Answer, ParseResult, Solution};
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    const DAY: u8 = {{number}};
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_one(_input: &Self::Input) -> Answer {
+        todo!()
+    }
+
+    fn part_two(_input: &Self::Input) -> Answer {
+        todo!()
+    }
+}